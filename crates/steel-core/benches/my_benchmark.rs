@@ -2,6 +2,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+use steel::parser::lexer::TokenStream;
 use steel::stdlib::PRELUDE;
 use steel::steel_vm::{engine::Engine, register_fn::RegisterFn};
 
@@ -312,6 +313,15 @@ fn binary_trees(c: &mut Criterion) {
     group.finish();
 }
 
+fn tokenize_large_source(c: &mut Criterion) {
+    // The prelude repeated a few times gives a reasonably large, realistic input
+    let source = PRELUDE.repeat(10);
+
+    c.bench_function("tokenize-large-source", |b| {
+        b.iter(|| TokenStream::new(black_box(&source), true, None).count())
+    });
+}
+
 criterion_group!(
     benches,
     range,
@@ -327,6 +337,7 @@ criterion_group!(
     register_function,
     multiple_transducers,
     binary_trees,
+    tokenize_large_source,
     // fib_28_contract,
     ackermann // trie_sort,
               // merge_sort,
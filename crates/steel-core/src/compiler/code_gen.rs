@@ -9,6 +9,7 @@ use crate::{
     },
     parser::{
         ast::{Atom, ExprKind, List},
+        interner::InternedString,
         parser::SyntaxObject,
         span_visitor::get_span,
         tokens::TokenType,
@@ -24,6 +25,7 @@ use steel_parser::tokens::MaybeBigInt;
 
 use super::{
     constants::ConstantMap,
+    modules::MANGLER_SEPARATOR,
     passes::analysis::{
         Analysis,
         CallKind::{Normal, SelfTailCall, TailCall},
@@ -39,11 +41,20 @@ fn fresh_function_id() -> usize {
     FUNCTION_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
+fn unmangled_name(name: InternedString) -> InternedString {
+    match name.resolve().rsplit_once(MANGLER_SEPARATOR) {
+        Some((_, rest)) => rest.into(),
+        None => name,
+    }
+}
+
 pub struct CodeGenerator<'a> {
     pub(crate) instructions: Vec<LabeledInstruction>,
     constant_map: &'a mut ConstantMap,
     analysis: &'a Analysis,
     local_count: Vec<usize>,
+    // The name of the define currently being compiled, if its body is a lambda
+    defining: Option<InternedString>,
 }
 
 /// Converts a syntax object's token into a `SteelVal` or returns an error if it is not a valid
@@ -100,6 +111,7 @@ impl<'a> CodeGenerator<'a> {
             constant_map,
             analysis,
             local_count: Vec::new(),
+            defining: None,
         }
     }
 
@@ -407,6 +419,12 @@ impl<'a> VisitorMut for CodeGenerator<'a> {
         if let ExprKind::Atom(name) = &define.name {
             self.push(LabeledInstruction::builder(OpCode::SDEF).contents(name.syn.clone()));
 
+            if let (ExprKind::LambdaFunction(_), TokenType::Identifier(ident)) =
+                (&define.body, &name.syn.ty)
+            {
+                self.defining = Some(*ident);
+            }
+
             self.visit(&define.body)?;
 
             // let defn_body_size = self.len() - sidx;
@@ -472,7 +490,20 @@ impl<'a> VisitorMut for CodeGenerator<'a> {
         //     LabeledInstruction::builder(OpCode::PASS).payload(lambda_function.syntax_object_id),
         // );
 
-        self.push(LabeledInstruction::builder(OpCode::PASS).payload(fresh_function_id()));
+        let function_id = fresh_function_id();
+
+        self.push(LabeledInstruction::builder(OpCode::PASS).payload(function_id));
+
+        // The name the function was bound to with `define` travels in the constant map,
+        // so it stays with the program wherever it ends up running. Anonymous functions
+        // point at void instead.
+        let name = match self.defining.take() {
+            Some(name) => SteelVal::SymbolV(unmangled_name(name).resolve().into()),
+            None => SteelVal::Void,
+        };
+        let name_idx = self.constant_map.add_or_get(name);
+
+        self.push(LabeledInstruction::builder(OpCode::PASS).payload(name_idx));
 
         // Save how many locals we have, for when we hit lets
         self.local_count.push(arity);
@@ -1010,9 +1041,10 @@ mod code_gen_tests {
         println!("{:#?}", code_gen.instructions);
 
         let expected = vec![
-            (OpCode::PUREFUNC, 9), // This captures no variables - should be able to be lifted as well
-            (OpCode::PASS, 0),     // multi arity
-            (OpCode::PASS, 0),     // This shouldn't need to be here
+            (OpCode::PUREFUNC, 10), // This captures no variables - should be able to be lifted as well
+            (OpCode::PASS, 0),      // multi arity
+            (OpCode::PASS, 0),      // This shouldn't need to be here
+            (OpCode::PASS, 0),      // name, anonymous functions point at void
             (OpCode::MOVEREADLOCAL, 0), // last usage of x, first var
             (OpCode::MOVEREADLOCAL, 1), // last usage of y
             (OpCode::MOVEREADLOCAL, 2), // last usage of z
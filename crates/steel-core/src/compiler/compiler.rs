@@ -298,6 +298,11 @@ pub struct Compiler {
     shadowed_variable_renamer: RenameShadowedVariables,
 
     search_dirs: Vec<PathBuf>,
+
+    // Directory that relative requires in programs without a path of their own are resolved
+    // against. When unset, the process working directory is used. The engine keeps this in
+    // step with the current directory of its VM before compiling.
+    current_directory: Option<PathBuf>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -367,6 +372,7 @@ impl Compiler {
             analysis: Analysis::pre_allocated(),
             shadowed_variable_renamer: RenameShadowedVariables::default(),
             search_dirs: Vec::new(),
+            current_directory: None,
         }
     }
 
@@ -391,6 +397,7 @@ impl Compiler {
             analysis: Analysis::pre_allocated(),
             shadowed_variable_renamer: RenameShadowedVariables::default(),
             search_dirs: Vec::new(),
+            current_directory: None,
         }
     }
 
@@ -434,6 +441,10 @@ impl Compiler {
         self.search_dirs.push(dir);
     }
 
+    pub fn set_current_directory(&mut self, dir: Option<PathBuf>) {
+        self.current_directory = dir;
+    }
+
     pub fn compile_executable_from_expressions(
         &mut self,
         exprs: Vec<ExprKind>,
@@ -549,6 +560,10 @@ impl Compiler {
         sources: &mut Sources,
         builtin_modules: ModuleContainer,
     ) -> Result<Vec<ExprKind>> {
+        // Programs without a path of their own resolve relative requires against
+        // the current directory of the compiler, if one has been set
+        let path = path.or_else(|| self.current_directory.clone());
+
         // #[cfg(feature = "modules")]
         return self.module_manager.compile_main(
             &mut self.macro_env,
@@ -1,5 +1,6 @@
+use crate::gc::Gc;
 use crate::parser::tryfrom_visitor::TryFromExprKindForSteelVal;
-use crate::rvals::{into_serializable_value, Result, SerializableSteelVal, SteelVal};
+use crate::rvals::{into_serializable_value, Result, SerializableSteelVal, SteelVal, SteelVector};
 
 use crate::parser::{
     ast::ExprKind,
@@ -154,7 +155,9 @@ impl ConstantMap {
         self.values.borrow_mut().push(val.clone());
 
         // TODO: Consider just storing the hash code, not the actual value.
-        self.map.borrow_mut().insert(val, idx);
+        if Self::is_shareable(&val) {
+            self.map.borrow_mut().insert(val, idx);
+        }
 
         idx
     }
@@ -169,6 +172,27 @@ impl ConstantMap {
         self.values.borrow().get(idx).cloned()
     }
 
+    // Quoted data can't be mutated in place - lists have no `set-car!` and vector literals
+    // are immutable - so structurally equal constants can share the same storage. Anything
+    // with interior mutability has to keep its own copy.
+    fn is_shareable(val: &SteelVal) -> bool {
+        match val {
+            SteelVal::BoolV(_)
+            | SteelVal::NumV(_)
+            | SteelVal::IntV(_)
+            | SteelVal::Rational(_)
+            | SteelVal::BigNum(_)
+            | SteelVal::BigRational(_)
+            | SteelVal::CharV(_)
+            | SteelVal::Void
+            | SteelVal::StringV(_)
+            | SteelVal::SymbolV(_) => true,
+            SteelVal::ListV(l) => l.iter().all(Self::is_shareable),
+            SteelVal::VectorV(v) => v.iter().all(Self::is_shareable),
+            _ => false,
+        }
+    }
+
     // Replace with existing constants if they already exist
     fn walk_constants(&mut self, val: &SteelVal) -> Option<SteelVal> {
         match val {
@@ -181,6 +205,15 @@ impl ConstantMap {
                     })
                     .collect(),
             )),
+            SteelVal::VectorV(v) => Some(SteelVal::VectorV(SteelVector(Gc::new(
+                v.iter()
+                    .map(|value| {
+                        let idx = self.add_or_get(value.clone());
+
+                        self.get(idx)
+                    })
+                    .collect(),
+            )))),
             _ => None,
         }
     }
@@ -188,10 +221,12 @@ impl ConstantMap {
     // This is certainly not what we want. This time complexity is
     // questionable
     pub fn add_or_get(&mut self, mut val: SteelVal) -> usize {
-        if let SteelVal::ListV(_) = &val {
-            if let Some(new_list) = self.walk_constants(&val) {
-                val = new_list;
-            };
+        if !Self::is_shareable(&val) {
+            return self.add(val);
+        }
+
+        if let Some(new_value) = self.walk_constants(&val) {
+            val = new_value;
         }
 
         let idx = self.map.borrow_mut().get(&val).copied();
@@ -242,6 +277,53 @@ pub mod constant_table_tests {
         assert_eq!(instance.add(val2), 1);
     }
 
+    #[test]
+    fn identical_literal_sublists_share_storage() {
+        let mut instance = ConstantMap::new();
+
+        let zero = || SteelVal::ListV(vec![SteelVal::IntV(0)].into());
+
+        let first = instance.add_or_get(SteelVal::ListV(vec![zero(), SteelVal::IntV(1)].into()));
+        let second = instance.add_or_get(SteelVal::ListV(vec![SteelVal::IntV(2), zero()].into()));
+
+        let (SteelVal::ListV(first), SteelVal::ListV(second)) =
+            (instance.get(first), instance.get(second))
+        else {
+            panic!("constants should still be lists");
+        };
+
+        assert!(first.car().unwrap().ptr_eq(second.iter().nth(1).unwrap()));
+
+        // The same literal is stored once
+        assert_eq!(instance.add_or_get(zero()), instance.add_or_get(zero()));
+    }
+
+    #[test]
+    fn identical_literal_vectors_share_storage() {
+        let mut instance = ConstantMap::new();
+
+        let vector = || {
+            SteelVal::VectorV(SteelVector(Gc::new(
+                vec![SteelVal::IntV(1), SteelVal::IntV(2)].into(),
+            )))
+        };
+
+        let first = instance.add_or_get(vector());
+        let second = instance.add_or_get(vector());
+
+        assert_eq!(first, second);
+        assert!(instance.get(first).ptr_eq(&instance.get(second)));
+    }
+
+    #[test]
+    fn mutable_values_are_not_shared() {
+        let mut instance = ConstantMap::new();
+
+        let boxed = || SteelVal::Boxed(Gc::new(std::cell::RefCell::new(SteelVal::IntV(1))));
+
+        assert_ne!(instance.add_or_get(boxed()), instance.add_or_get(boxed()));
+    }
+
     fn test_get(instance: &mut ConstantMap) {
         assert_eq!(instance.len(), 0);
         let val1 = SteelVal::BoolV(true);
@@ -1,5 +1,9 @@
 use crate::throw;
-use crate::{parser::interner::InternedString, rvals::Result};
+use crate::{
+    compiler::program::{RAW_UNQUOTE, RAW_UNQUOTE_SPLICING},
+    parser::interner::InternedString,
+    rvals::Result,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -50,9 +54,15 @@ impl SymbolMap {
 
     // fallible
     pub fn get(&self, ident: &InternedString) -> Result<usize> {
-        self.map
-            .get(ident)
-            .copied()
-            .ok_or_else(throw!(FreeIdentifier => ident.resolve()))
+        self.map.get(ident).copied().ok_or_else(|| {
+            // An `unquote` that isn't inside of a `quasiquote` is left behind by the reader
+            // as a call to one of these, which are never bound.
+            if *ident == *RAW_UNQUOTE || *ident == *RAW_UNQUOTE_SPLICING {
+                let name = ident.resolve().trim_start_matches("#%");
+                throw!(BadSyntax => "{}: not in quasiquote", name)()
+            } else {
+                throw!(FreeIdentifier => ident.resolve())()
+            }
+        })
     }
 }
@@ -292,10 +292,11 @@ pub fn flatten_begins_and_expand_defines(
         .into_iter()
         .map(|mut x| {
             FlattenBegin::flatten(&mut x);
-            x
+            CheckForwardReferences::check(&x).map(|_| x)
         })
-        .map(ConvertDefinesToLets::convert_defines)
-        .map(|mut x| {
+        .map(|x| x.map(ConvertDefinesToLets::convert_defines))
+        .map(|x| {
+            let mut x = x?;
             let mut checker = CheckDefinesAreInLegalPositions { depth: 0 };
             checker.visit(&mut x)?;
             Ok(x)
@@ -420,6 +421,135 @@ impl Folder for ConvertDefinesToLets {
     }
 }
 
+// Internal defines behave like `letrec*`: every name in the body is in scope for every
+// initializer, but only the ones that come earlier have actually been initialized. The
+// define conversion binds the later names to a dummy value, so evaluating one of them
+// directly (rather than from inside a lambda) would silently produce garbage. Catch that
+// before the conversion and report it the same way we do for top level definitions.
+struct CheckForwardReferences {
+    depth: usize,
+    error: Option<crate::rerrs::SteelErr>,
+}
+
+impl CheckForwardReferences {
+    fn check(expr: &ExprKind) -> crate::rvals::Result<()> {
+        let mut checker = CheckForwardReferences {
+            depth: 0,
+            error: None,
+        };
+
+        checker.visit(expr);
+
+        match checker.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl VisitorMutUnit for CheckForwardReferences {
+    fn visit_lambda_function(&mut self, lambda_function: &LambdaFunction) {
+        self.depth += 1;
+        self.visit(&lambda_function.body);
+        self.depth -= 1;
+    }
+
+    fn visit_let(&mut self, l: &Let) {
+        self.depth += 1;
+        l.bindings.iter().for_each(|x| self.visit(&x.1));
+        self.visit(&l.body_expr);
+        self.depth -= 1;
+    }
+
+    fn visit_begin(&mut self, begin: &Begin) {
+        if self.error.is_some() {
+            return;
+        }
+
+        if self.depth > 0 {
+            let defined = begin
+                .exprs
+                .iter()
+                .map(|expr| match expr {
+                    ExprKind::Define(d) => d.name.atom_identifier().copied(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            for (i, expr) in begin.exprs.iter().enumerate() {
+                let d = match expr {
+                    ExprKind::Define(d) => d,
+                    _ => continue,
+                };
+
+                let later = defined[i + 1..].iter().flatten().copied().collect();
+                let mut finder = FindReference {
+                    names: later,
+                    found: None,
+                };
+                finder.visit(&d.body);
+
+                if let Some((name, span)) = finder.found {
+                    let message =
+                        format!("Cannot reference an identifier before its definition: {name}");
+                    self.error = Some(
+                        crate::rerrs::SteelErr::new(
+                            crate::rerrs::ErrorKind::FreeIdentifier,
+                            message,
+                        )
+                        .with_span(span),
+                    );
+                    return;
+                }
+            }
+        }
+
+        for expr in &begin.exprs {
+            self.visit(expr);
+        }
+    }
+}
+
+// Looks for a reference to any of `names` that would be evaluated immediately, i.e. not
+// one sitting inside of a lambda body or a quoted datum.
+struct FindReference {
+    names: smallvec::SmallVec<[InternedString; 8]>,
+    found: Option<(InternedString, crate::parser::span::Span)>,
+}
+
+impl VisitorMutUnit for FindReference {
+    fn visit_lambda_function(&mut self, _lambda_function: &LambdaFunction) {}
+
+    fn visit_quote(&mut self, _quote: &Quote) {}
+
+    fn visit_let(&mut self, l: &Let) {
+        l.bindings.iter().for_each(|x| self.visit(&x.1));
+
+        let shadowed = l.bindings.iter().any(|(binding, _)| {
+            binding
+                .atom_identifier()
+                .map(|name| self.names.contains(name))
+                .unwrap_or(false)
+        });
+
+        if !shadowed {
+            self.visit(&l.body_expr);
+        }
+    }
+
+    fn visit_atom(&mut self, a: &Atom) {
+        if self.found.is_some() {
+            return;
+        }
+
+        if let TokenType::Identifier(ident) = &a.syn.ty {
+            if self.names.contains(ident) {
+                self.found = Some((*ident, a.syn.span));
+            }
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
 enum ExpressionType {
     DefineConst(InternedString),
@@ -47,7 +47,7 @@ impl VisitorMutRefUnit for MultipleArityFunctions {
 
         // If we found one dot in the correct position
         // Adjust this lambda function to be a rest function
-        if dot_count == 1 {
+        if dot_count == 1 && lambda_function.args.len() >= 2 {
             let dot_index = lambda_function.args.len() - 2;
 
             if let Some(dot) = lambda_function.args[dot_index].atom_identifier() {
@@ -407,6 +407,10 @@ define_symbols! {
     DEFINE_VALUES => "define-values",
     AS_KEYWORD => "as",
     SYNTAX_CONST_IF => "syntax-const-if",
+    LET_SYNTAX => "let-syntax",
+    LETREC_SYNTAX => "letrec-syntax",
+    SYNTAX_ERROR => "syntax-error",
+    LOCAL_BINDINGS => "local-bindings",
     UNQUOTE => "unquote",
     UNQUOTE_COMMA => "#%unquote-comma",
     RAW_UNQUOTE => "#%unquote",
@@ -1,22 +1,27 @@
 use fxhash::{FxBuildHasher, FxHashMap, FxHashSet};
 use quickscope::ScopeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use steel_parser::ast::{parse_lambda, Begin};
-use steel_parser::parser::SourceId;
+use steel_parser::parser::{lower_syntax_rules, SourceId};
 
 use crate::compiler::passes::reader::MultipleArityFunctions;
 use crate::compiler::passes::VisitorMutRefUnit;
 use crate::parser::ast::ExprKind;
 use crate::parser::parser::SyntaxObject;
+use crate::parser::span::Span;
 use crate::parser::span_visitor::get_span;
 use crate::steel_vm::engine::ModuleContainer;
-use crate::{compiler::program::AS_KEYWORD, parser::tokens::TokenType};
 use crate::{compiler::program::REQUIRE_BUILTIN, rvals::Result};
+use crate::{
+    compiler::program::{AS_KEYWORD, LETREC_SYNTAX, LET_SYNTAX, LOCAL_BINDINGS, SYNTAX_ERROR},
+    parser::tokens::TokenType,
+};
 
 use steel_parser::expr_list;
 
 use super::visitors::VisitorMutRef;
 use super::{
-    ast::{Atom, Define, LambdaFunction, List, Quote},
+    ast::{Atom, Define, LambdaFunction, List, Macro, Quote},
     interner::InternedString,
     kernel::Kernel,
 };
@@ -84,8 +89,10 @@ pub fn expand(expr: &mut ExprKind, map: &FxHashMap<InternedString, SteelMacro>)
     let mut expander = Expander {
         depth: 0,
         map,
+        local_macros: FxHashMap::default(),
         changed: false,
         in_scope_values: ScopeSet::default(),
+        frames: Vec::new(),
         source_id: None,
     };
     expander.visit(expr)
@@ -99,8 +106,10 @@ pub fn expand_with_source_id(
     let mut expander = Expander {
         depth: 0,
         map,
+        local_macros: FxHashMap::default(),
         changed: false,
         in_scope_values: ScopeSet::default(),
+        frames: Vec::new(),
         source_id: Some(source_id),
     };
 
@@ -109,9 +118,14 @@ pub fn expand_with_source_id(
 
 pub struct Expander<'a> {
     map: &'a FxHashMap<InternedString, SteelMacro>,
+    // Macros introduced by `let-syntax` and `letrec-syntax`, only visible
+    // while expanding the body of the form that introduced them
+    local_macros: FxHashMap<InternedString, SteelMacro>,
     pub(crate) changed: bool,
     // We're going to actually check if the macro is in scope
     in_scope_values: ScopeSet<InternedString, FxBuildHasher>,
+    // The names bound by each enclosing binding form, innermost last, for `(local-bindings)`
+    frames: Vec<Vec<InternedString>>,
     source_id: Option<SourceId>,
     depth: usize,
 }
@@ -120,8 +134,10 @@ impl<'a> Expander<'a> {
     pub fn new(map: &'a FxHashMap<InternedString, SteelMacro>) -> Self {
         Self {
             map,
+            local_macros: FxHashMap::default(),
             changed: false,
             in_scope_values: ScopeSet::default(),
+            frames: Vec::new(),
             source_id: None,
             depth: 0,
         }
@@ -130,6 +146,375 @@ impl<'a> Expander<'a> {
     pub fn expand(&mut self, expr: &mut ExprKind) -> Result<()> {
         self.visit(expr)
     }
+
+    fn push_scope(&mut self, names: impl IntoIterator<Item = InternedString>, body: &[ExprKind]) {
+        self.in_scope_values.push_layer();
+
+        let mut frame = Vec::new();
+
+        for name in names {
+            self.in_scope_values.define(name);
+
+            if !frame.contains(&name) {
+                frame.push(name);
+            }
+        }
+
+        // Definitions at the top of the body belong to the same frame as the arguments
+        for name in internal_definitions(body) {
+            if !frame.contains(&name) {
+                frame.push(name);
+            }
+        }
+
+        self.frames.push(frame);
+    }
+
+    fn pop_scope(&mut self) {
+        self.in_scope_values.pop_layer();
+        self.frames.pop();
+    }
+
+    // `(local-bindings)` becomes an association list of the names bound by the innermost
+    // binding form, paired with their current values:
+    //
+    // (let ([a 1] [b 2]) (local-bindings)) => (list (cons 'a a) (cons 'b b))
+    fn local_bindings(&self) -> ExprKind {
+        let mut pairs = vec![ExprKind::atom("list")];
+
+        for name in self.frames.last().into_iter().flatten() {
+            pairs.push(expr_list![
+                ExprKind::atom("cons"),
+                ExprKind::Quote(Box::new(Quote::new(
+                    ExprKind::atom(*name),
+                    SyntaxObject::default(TokenType::Quote)
+                ))),
+                ExprKind::atom(*name),
+            ]);
+        }
+
+        ExprKind::List(List::new(pairs))
+    }
+
+    // Expands (let-syntax ((name (syntax-rules ...)) ...) body ...) by installing the
+    // macros for the duration of the body, and then replacing the whole form with the
+    // expanded body wrapped in a thunk, so that definitions in the body stay local.
+    //
+    // Expansion results are expanded again in the same environment, so with `letrec-syntax`
+    // the macros can refer to each other and to themselves. With `let-syntax` only the body
+    // should see the new bindings - a template that uses one of the names being bound refers
+    // to whatever macro had that name outside of the form, so those uses are renamed to a
+    // fresh alias for the outer macro before the transformers are installed.
+    fn expand_local_syntax(&mut self, expr: &mut ExprKind, span: Span) -> Result<()> {
+        let mut args = match expr {
+            ExprKind::List(l) => std::mem::take(&mut l.args).into_iter(),
+            _ => unreachable!(),
+        };
+        let form = args.next();
+
+        let recursive = matches!(
+            form.as_ref().and_then(|x| x.atom_identifier()),
+            Some(s) if *s == *LETREC_SYNTAX
+        );
+
+        let bindings = match args.next() {
+            Some(ExprKind::List(bindings)) => bindings.args,
+            _ => {
+                stop!(BadSyntax => format!("{} expects a list of macro bindings", form.unwrap()); span)
+            }
+        };
+
+        let body: Vec<_> = args.collect();
+
+        if body.is_empty() {
+            stop!(BadSyntax => format!("{} expects at least one body expression", form.unwrap()); span)
+        }
+
+        let mut transformers = Vec::with_capacity(bindings.len());
+
+        for binding in bindings {
+            let mut pair = match binding {
+                ExprKind::List(pair) if pair.len() == 2 => pair.args.into_iter(),
+                other => {
+                    stop!(BadSyntax => format!("expected a macro binding of the form (name (syntax-rules ...)), found: {}", other); span)
+                }
+            };
+
+            let name = pair.next().unwrap();
+
+            let syntax_rules = match pair.next().unwrap() {
+                ExprKind::SyntaxRules(s) => s,
+                rules @ ExprKind::List(_) => Box::new(lower_syntax_rules(rules)?),
+                other => {
+                    stop!(BadSyntax => format!("expected a syntax-rules form, found: {}", other); span)
+                }
+            };
+
+            transformers.push((name, syntax_rules));
+        }
+
+        let mut shadowed = Vec::new();
+
+        if !recursive {
+            let mut renamer = RenameOuterMacros::default();
+
+            for name in transformers
+                .iter()
+                .filter_map(|(name, _)| name.atom_identifier())
+            {
+                if self.in_scope_values.contains(name) || renamer.aliases.contains_key(name) {
+                    continue;
+                }
+
+                let outer = self
+                    .local_macros
+                    .get(name)
+                    .or_else(|| self.map.get(name))
+                    .cloned();
+
+                if let Some(outer) = outer {
+                    let alias: InternedString = format!(
+                        "#%outer-macro-{}-{}",
+                        name.resolve(),
+                        OUTER_MACRO_ID.fetch_add(1, Ordering::Relaxed)
+                    )
+                    .into();
+
+                    renamer.aliases.insert(*name, alias);
+                    shadowed.push((alias, self.local_macros.insert(alias, outer)));
+                }
+            }
+
+            if !renamer.aliases.is_empty() {
+                for (_, syntax_rules) in transformers.iter_mut() {
+                    for pair in syntax_rules.patterns.iter_mut() {
+                        renamer.bound_by_pattern.clear();
+
+                        // The head of the pattern is the macro keyword, not a variable
+                        if let ExprKind::List(pattern) = &pair.pattern {
+                            for expr in pattern.args.iter().skip(1) {
+                                pattern_variables(expr, &mut renamer.bound_by_pattern);
+                            }
+                        }
+
+                        renamer.visit(&mut pair.body);
+                    }
+                }
+            }
+        }
+
+        for (name, syntax_rules) in transformers {
+            let generated_macro = SteelMacro::parse_from_ast_macro(Box::new(Macro::new(
+                name,
+                syntax_rules,
+                SyntaxObject::new(TokenType::DefineSyntax, span),
+            )))?;
+
+            let name = *generated_macro.name();
+
+            shadowed.push((name, self.local_macros.insert(name, generated_macro)));
+        }
+
+        let mut lambda = LambdaFunction::new(
+            Vec::new(),
+            ExprKind::Begin(Begin::new(body, SyntaxObject::default(TokenType::Begin))),
+            SyntaxObject::new(TokenType::Lambda, span),
+        );
+
+        let result = self.visit_lambda_function(&mut lambda);
+
+        // Restore whatever the local macros shadowed, in reverse order so that
+        // duplicate names in the same form unwind correctly
+        for (name, previous) in shadowed.into_iter().rev() {
+            match previous {
+                Some(previous) => self.local_macros.insert(name, previous),
+                None => self.local_macros.remove(&name),
+            };
+        }
+
+        result?;
+
+        self.changed = true;
+
+        *expr = ExprKind::List(List::new(vec![ExprKind::LambdaFunction(Box::new(lambda))]));
+
+        Ok(())
+    }
+
+    // Visits the shorthand `(define (name arg ...) body ...)` before it has been lowered,
+    // with the arguments in scope for the body, the same as for a `lambda`.
+    fn visit_function_define_list(&mut self, l: &mut List) -> Result<()> {
+        let mut names = Vec::new();
+
+        if let Some(ExprKind::List(signature)) = l.args.get(1) {
+            for arg in signature.args.iter().skip(1) {
+                if let Some(ident) = arg.atom_identifier() {
+                    names.push(*ident);
+                }
+            }
+        }
+
+        self.push_scope(names, l.args.get(2..).unwrap_or(&[]));
+
+        let result = l
+            .args
+            .iter_mut()
+            .skip(2)
+            .try_for_each(|expr| self.visit(expr));
+
+        self.pop_scope();
+
+        result
+    }
+
+    // Visits a `let` that hasn't been lowered yet, either `(let ([x e] ...) body ...)` or the
+    // named `(let name ([x e] ...) body ...)`. The bound names are in scope for the body, so
+    // that a macro used there knows they're values, which stops literals like `else` matching.
+    fn visit_let_list(&mut self, l: &mut List) -> Result<()> {
+        let bindings_index = match l.args.get(1).and_then(|x| x.atom_identifier()) {
+            Some(_) => 2,
+            None => 1,
+        };
+
+        let mut names = Vec::new();
+
+        match l.args.get_mut(bindings_index) {
+            Some(ExprKind::List(bindings)) => {
+                for binding in bindings.args.iter_mut() {
+                    match binding {
+                        ExprKind::List(pair) if pair.len() == 2 => {
+                            if let Some(name) = pair.args[0].atom_identifier() {
+                                names.push(*name);
+                            }
+
+                            self.visit(&mut pair.args[1])?;
+                        }
+                        other => self.visit(other)?,
+                    }
+                }
+            }
+            Some(other) => self.visit(other)?,
+            None => {}
+        }
+
+        self.push_scope(names, l.args.get(bindings_index + 1..).unwrap_or(&[]));
+
+        // The loop name of a named let is in scope for the body, but it isn't one of the
+        // bindings of the frame, so it's left out of `(local-bindings)`
+        if let Some(name) = l.args.get(1).and_then(|x| x.atom_identifier()) {
+            self.in_scope_values.define(*name);
+        }
+
+        let result = l
+            .args
+            .iter_mut()
+            .skip(bindings_index + 1)
+            .try_for_each(|expr| self.visit(expr));
+
+        self.pop_scope();
+
+        result
+    }
+}
+
+// The names defined directly in a body, including inside a `begin`, whether with
+// `(define name value)` or the `(define (name arg ...) body ...)` shorthand
+fn internal_definitions(body: &[ExprKind]) -> Vec<InternedString> {
+    let mut names = Vec::new();
+
+    for expr in body {
+        match expr {
+            ExprKind::Define(define) => names.extend(define.name.atom_identifier().copied()),
+            ExprKind::Begin(begin) => names.extend(internal_definitions(&begin.exprs)),
+            ExprKind::List(l) => {
+                let is_define = matches!(
+                    l.args.first(),
+                    Some(ExprKind::Atom(Atom {
+                        syn: SyntaxObject {
+                            ty: TokenType::Define,
+                            ..
+                        },
+                    }))
+                );
+
+                let name = match l.args.get(1) {
+                    Some(ExprKind::List(signature)) => signature.args.first(),
+                    other => other,
+                };
+
+                if is_define {
+                    names.extend(name.and_then(|x| x.atom_identifier()).copied());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+static OUTER_MACRO_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Points the uses of macros in a `let-syntax` template at the aliases for the macros
+// that were visible outside of the form, leaving pattern variables and quoted data alone
+#[derive(Default)]
+struct RenameOuterMacros {
+    aliases: FxHashMap<InternedString, InternedString>,
+    bound_by_pattern: FxHashSet<InternedString>,
+}
+
+impl VisitorMutRefUnit for RenameOuterMacros {
+    fn visit_quote(&mut self, _quote: &mut Quote) {}
+
+    fn visit_list(&mut self, l: &mut List) {
+        if let Some(head) = l.args.first_mut().and_then(|x| x.atom_identifier_mut()) {
+            if !self.bound_by_pattern.contains(head) {
+                if let Some(alias) = self.aliases.get(head) {
+                    *head = *alias;
+                }
+            }
+        }
+
+        for expr in l.args.iter_mut() {
+            self.visit(expr);
+        }
+    }
+}
+
+fn pattern_variables(pattern: &ExprKind, variables: &mut FxHashSet<InternedString>) {
+    match pattern {
+        ExprKind::Atom(_) => variables.extend(pattern.atom_identifier().copied()),
+        ExprKind::List(l) => {
+            for expr in l.args.iter() {
+                pattern_variables(expr, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Builds the message for `(syntax-error "message" irritant ...)`, which a macro can expand
+// into in order to reject a use of the macro at expansion time
+fn syntax_error_message(args: &[ExprKind]) -> String {
+    let mut parts = args.iter().map(|arg| match arg {
+        ExprKind::Atom(Atom {
+            syn:
+                SyntaxObject {
+                    ty: TokenType::StringLiteral(s),
+                    ..
+                },
+        }) => s.to_string(),
+        other => other.to_string(),
+    });
+
+    let mut message = parts.next().unwrap_or_else(|| "syntax error".to_string());
+
+    for irritant in parts {
+        message.push(' ');
+        message.push_str(&irritant);
+    }
+
+    message
 }
 
 impl<'a> VisitorMutRef for Expander<'a> {
@@ -203,6 +588,67 @@ impl<'a> VisitorMutRef for Expander<'a> {
                             unreachable!()
                         }
                     }
+                    Some(ExprKind::Atom(Atom {
+                        syn:
+                            SyntaxObject {
+                                ty: TokenType::Let, ..
+                            },
+                    })) => {
+                        return self.visit_let_list(l);
+                    }
+                    Some(ExprKind::Atom(Atom {
+                        syn:
+                            SyntaxObject {
+                                ty: TokenType::Define,
+                                ..
+                            },
+                    })) if matches!(l.args.get(1), Some(ExprKind::List(_))) => {
+                        return self.visit_function_define_list(l);
+                    }
+                    Some(ExprKind::Atom(Atom {
+                        syn:
+                            SyntaxObject {
+                                ty: TokenType::Identifier(s),
+                                span: sp,
+                                ..
+                            },
+                    })) if (*s == *LET_SYNTAX || *s == *LETREC_SYNTAX)
+                        && !self.in_scope_values.contains(s) =>
+                    {
+                        let span = *sp;
+                        return self.expand_local_syntax(expr, span);
+                    }
+                    Some(ExprKind::Atom(Atom {
+                        syn:
+                            SyntaxObject {
+                                ty: TokenType::Identifier(s),
+                                span: sp,
+                                ..
+                            },
+                    })) if *s == *SYNTAX_ERROR && !self.in_scope_values.contains(s) => {
+                        stop!(BadSyntax => syntax_error_message(&l.args[1..]); *sp)
+                    }
+                    Some(ExprKind::Atom(Atom {
+                        syn:
+                            SyntaxObject {
+                                ty: TokenType::Identifier(s),
+                                span: sp,
+                                ..
+                            },
+                    })) if *s == *LOCAL_BINDINGS
+                        && !self.in_scope_values.contains(s)
+                        && !self.local_macros.contains_key(s)
+                        && !self.map.contains_key(s) =>
+                    {
+                        if l.args.len() != 1 {
+                            stop!(ArityMismatch => "local-bindings takes no arguments"; *sp)
+                        }
+
+                        *expr = self.local_bindings();
+                        self.changed = true;
+
+                        return Ok(());
+                    }
                     Some(ExprKind::Atom(Atom {
                         syn:
                             SyntaxObject {
@@ -211,7 +657,29 @@ impl<'a> VisitorMutRef for Expander<'a> {
                                 ..
                             },
                     })) => {
-                        if let Some(m) = self.map.get(s) {
+                        if let Some(m) = self.local_macros.get(s) {
+                            // Local macros always win, unless shadowed by a local value
+                            if !self.in_scope_values.contains(s) {
+                                let span = *sp;
+
+                                let mut expanded = m.expand_in_scope(
+                                    List::new(std::mem::take(&mut l.args)),
+                                    span,
+                                    &|s| self.in_scope_values.contains(s),
+                                )?;
+                                self.changed = true;
+
+                                self.depth += 1;
+
+                                self.visit(&mut expanded)?;
+
+                                self.depth -= 1;
+
+                                *expr = expanded;
+
+                                return Ok(());
+                            }
+                        } else if let Some(m) = self.map.get(s) {
                             // println!("Macro: {} - source id: {:?}", s, sp.source_id());
                             // println!("Source id: {:?}", self.source_id);
 
@@ -224,8 +692,11 @@ impl<'a> VisitorMutRef for Expander<'a> {
                                 {
                                     let span = *sp;
 
-                                    let mut expanded =
-                                        m.expand(List::new(std::mem::take(&mut l.args)), span)?;
+                                    let mut expanded = m.expand_in_scope(
+                                        List::new(std::mem::take(&mut l.args)),
+                                        span,
+                                        &|s| self.in_scope_values.contains(s),
+                                    )?;
                                     self.changed = true;
 
                                     self.depth += 1;
@@ -282,19 +753,20 @@ impl<'a> VisitorMutRef for Expander<'a> {
         &mut self,
         lambda_function: &mut super::ast::LambdaFunction,
     ) -> Self::Output {
-        self.in_scope_values.push_layer();
+        let names = lambda_function
+            .args
+            .iter()
+            .filter_map(|x| x.atom_identifier())
+            .copied()
+            .collect::<Vec<_>>();
 
-        for value in &lambda_function.args {
-            if let Some(ident) = value.atom_identifier() {
-                self.in_scope_values.define(*ident);
-            }
-        }
+        self.push_scope(names, std::slice::from_ref(&lambda_function.body));
 
-        self.visit(&mut lambda_function.body)?;
+        let result = self.visit(&mut lambda_function.body);
 
-        self.in_scope_values.pop_layer();
+        self.pop_scope();
 
-        Ok(())
+        result
     }
 
     fn visit_begin(&mut self, begin: &mut super::ast::Begin) -> Self::Output {
@@ -957,6 +1429,16 @@ impl<'a> VisitorMutRef for KernelExpander<'a> {
                                 }
                             }
                         }
+
+                        // The bindings are syntax-rules, which are handled by the syntax-rules
+                        // expander - only the body is relevant here.
+                        if s == *LET_SYNTAX || s == *LETREC_SYNTAX {
+                            for expr in l.args.iter_mut().skip(2) {
+                                self.visit(expr)?;
+                            }
+
+                            return Ok(());
+                        }
                     }
 
                     for expr in l.args.iter_mut() {
@@ -224,12 +224,12 @@ impl SteelMacro {
 
     // TODO the case matching should be a little bit more informed than this
     // I think it should also not be greedy, and should report if there are ambiguous matchings
-    fn match_case(&self, expr: &List) -> Result<&MacroCase> {
+    fn match_case(&self, expr: &List, is_bound: BoundPredicate<'_>) -> Result<&MacroCase> {
         for case in &self.cases {
             if (case.has_ellipses() && expr.len() >= (case.arity() - 1))
                 || case.arity() == expr.len()
             {
-                if case.recursive_match(expr) {
+                if case.recursive_match(expr, is_bound) {
                     return Ok(case);
                 }
             }
@@ -244,13 +244,25 @@ impl SteelMacro {
     }
 
     pub fn expand(&self, expr: List, span: Span) -> Result<ExprKind> {
+        self.expand_in_scope(expr, span, &|_| false)
+    }
+
+    /// Expands the macro, where `is_bound` reports whether an identifier is bound to a
+    /// value at the use site. A literal like `else` only matches when it isn't bound, so
+    /// that `(cond [else 1])` treats a local variable named `else` as an ordinary test.
+    pub(crate) fn expand_in_scope(
+        &self,
+        expr: List,
+        span: Span,
+        is_bound: BoundPredicate<'_>,
+    ) -> Result<ExprKind> {
         // if log::log_enabled!(log::Level::Debug) {
         //     log::debug!("Expanding macro with tokens: {}", expr);
         // }
 
         // log::debug!("Expanding with span: {:?}", span);
 
-        let case_to_expand = self.match_case(&expr)?;
+        let case_to_expand = self.match_case(&expr, is_bound)?;
         let expanded_expr = case_to_expand.expand(expr, span)?;
 
         // if log::log_enabled!(log::Level::Debug) {
@@ -324,9 +336,9 @@ impl MacroCase {
             .sum()
     }
 
-    fn recursive_match(&self, list: &List) -> bool {
+    fn recursive_match(&self, list: &List, is_bound: BoundPredicate<'_>) -> bool {
         // Don't match on the first argument
-        match_vec_pattern(&self.args[1..], &list.args[1..])
+        match_vec_pattern_in_scope(&self.args[1..], &list.args[1..], is_bound)
     }
 
     fn expand(&self, expr: List, span: Span) -> Result<ExprKind> {
@@ -635,7 +647,18 @@ impl MacroPattern {
     }
 }
 
+// Reports whether an identifier is bound to a value where the macro is being used
+pub(crate) type BoundPredicate<'a> = &'a dyn Fn(&InternedString) -> bool;
+
 pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
+    match_vec_pattern_in_scope(args, list, &|_| false)
+}
+
+fn match_vec_pattern_in_scope(
+    args: &[MacroPattern],
+    list: &[ExprKind],
+    is_bound: BoundPredicate<'_>,
+) -> bool {
     let mut token_iter = list.iter();
 
     for pat in args {
@@ -645,7 +668,7 @@ pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
 
             match pat {
                 MacroPattern::Single(_) | MacroPattern::Many(_) => continue,
-                // TODO: @Matt - if the atom is bound locally, then do not match on this
+                // A literal that is bound locally is just a variable, so it doesn't match
                 MacroPattern::Syntax(v) => match val {
                     ExprKind::Atom(Atom {
                         syn:
@@ -653,7 +676,7 @@ pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
                                 ty: TokenType::Identifier(s),
                                 ..
                             },
-                    }) if s == v => continue,
+                    }) if s == v && !is_bound(s) => continue,
                     ExprKind::Atom(Atom {
                         syn:
                             SyntaxObject {
@@ -799,7 +822,7 @@ pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
                         }
 
                         // Make the recursive call on the next layer
-                        if match_vec_pattern(vec, l) {
+                        if match_vec_pattern_in_scope(vec, l, is_bound) {
                             continue;
                         } else {
                             // log::debug!("Matching failed due to child not matching");
@@ -816,7 +839,7 @@ pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
                 }
                 MacroPattern::ManyNested(vec) => {
                     if let ExprKind::List(l) = val {
-                        if !match_vec_pattern(vec, l) {
+                        if !match_vec_pattern_in_scope(vec, l, is_bound) {
                             return false;
                         }
                     } else {
@@ -825,7 +848,7 @@ pub fn match_vec_pattern(args: &[MacroPattern], list: &[ExprKind]) -> bool {
 
                     for maybe_next in token_iter {
                         if let ExprKind::List(l) = maybe_next {
-                            if match_vec_pattern(vec, l) {
+                            if match_vec_pattern_in_scope(vec, l, is_bound) {
                                 continue;
                             }
                         } else {
@@ -1180,6 +1203,30 @@ mod match_vec_pattern_tests {
 
         assert!(!match_vec_pattern(&pattern_args, &list_expr));
     }
+
+    #[test]
+    fn test_bound_literal_does_not_match() {
+        let pattern_args = vec![
+            MacroPattern::Syntax("cond".into()),
+            MacroPattern::Nested(vec![
+                MacroPattern::Syntax("else".into()),
+                MacroPattern::Single("e".into()),
+            ]),
+        ];
+        let list_expr = List::new(vec![
+            atom_identifier("cond"),
+            ExprKind::List(List::new(vec![atom_identifier("else"), atom_int(1)])),
+        ]);
+
+        assert!(match_vec_pattern(&pattern_args, &list_expr));
+
+        let else_symbol: InternedString = "else".into();
+        assert!(!match_vec_pattern_in_scope(
+            &pattern_args,
+            &list_expr,
+            &|s| *s == else_symbol
+        ));
+    }
 }
 
 #[cfg(test)]
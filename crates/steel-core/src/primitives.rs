@@ -47,7 +47,7 @@ use std::convert::TryFrom;
 use std::result;
 pub use streams::StreamOperations;
 pub use strings::string_module;
-pub use symbols::SymbolOperations;
+pub use symbols::{reset_gensym_counter, SymbolOperations};
 pub use vectors::VectorOperations;
 
 macro_rules! try_from_impl {
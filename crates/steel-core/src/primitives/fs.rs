@@ -1,7 +1,6 @@
 use crate::rvals::{Custom, Result, SteelString, SteelVal};
 use crate::steel_vm::builtin::BuiltInModule;
 use crate::stop;
-use std::env::current_dir;
 use std::path::{Path, PathBuf};
 
 use std::fs;
@@ -51,7 +50,10 @@ pub fn fs_module() -> BuiltInModule {
         .register_native_fn_definition(READ_DIR_DEFINITION)
         .register_native_fn_definition(PATH_EXISTS_DEFINITION)
         .register_native_fn_definition(FILE_NAME_DEFINITION)
-        .register_native_fn_definition(CURRENT_DIRECTORY_DEFINITION)
+        .register_value(
+            "current-directory",
+            SteelVal::BuiltIn(crate::steel_vm::vm::current_directory),
+        )
         .register_native_fn_definition(GET_EXTENSION_DEFINITION);
     module
 }
@@ -143,10 +145,3 @@ pub fn read_dir(path: &SteelString) -> Result<SteelVal> {
         stop!(TypeMismatch => "read-dir expected a dir, found a file: {}", path)
     }
 }
-
-/// Check the current working directory
-#[steel_derive::function(name = "current-directory")]
-pub fn current_directory() -> Result<SteelVal> {
-    let path = current_dir()?;
-    Ok(SteelVal::StringV(path.to_str().unwrap_or("").into()))
-}
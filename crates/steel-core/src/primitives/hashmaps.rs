@@ -1,11 +1,16 @@
-use crate::rvals::SteelHashMap;
+use crate::rvals::cycles::BreadthFirstSearchSteelValVisitor;
+use crate::rvals::{Custom, SteelHashMap};
+use crate::steel_vm::register_fn::RegisterFn;
 use crate::stop;
 use crate::{core::utils::declare_const_ref_functions, gc::Gc};
 use crate::{
-    rvals::{Result, SteelVal},
+    rvals::{RestArgsIter, Result, SteelVal},
     steel_vm::builtin::BuiltInModule,
+    values::lists::Pair,
 };
 use im_rc::HashMap;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 use crate::primitives::VectorOperations;
 
@@ -29,6 +34,8 @@ pub(crate) fn hashmap_module() -> BuiltInModule {
         .register_value("hash-get", SteelVal::FuncV(steel_hash_ref))
         .register_native_fn_definition(HASH_TRY_GET_DEFINITION)
         .register_native_fn_definition(HASH_LENGTH_DEFINITION)
+        .register_native_fn_definition(HASH_COUNT_DEFINITION)
+        .register_native_fn_definition(HASH_REMOVE_DEFINITION)
         .register_native_fn_definition(HASH_CONTAINS_DEFINITION)
         .register_native_fn_definition(KEYS_TO_LIST_DEFINITION)
         .register_native_fn_definition(KEYS_TO_VECTOR_DEFINITION)
@@ -36,7 +43,19 @@ pub(crate) fn hashmap_module() -> BuiltInModule {
         .register_native_fn_definition(VALUES_TO_VECTOR_DEFINITION)
         .register_native_fn_definition(CLEAR_DEFINITION)
         .register_native_fn_definition(HM_EMPTY_DEFINITION)
-        .register_native_fn_definition(HM_UNION_DEFINITION);
+        .register_native_fn_definition(HM_UNION_DEFINITION)
+        .register_native_fn_definition(EQUAL_HASH_DEFINITION)
+        .register_native_fn_definition(HASH_TO_LIST_DEFINITION)
+        .register_fn("make-hash", HashTable::equal)
+        .register_fn("make-equal-hash", HashTable::equal)
+        .register_fn("make-eqv-hash", HashTable::eqv)
+        .register_fn("hash-table-set!", HashTable::insert)
+        .register_fn("hash-table-ref", HashTable::get_value)
+        .register_fn("hash-table-ref/default", HashTable::get_or_default)
+        .register_fn("hash-table-contains?", HashTable::contains)
+        .register_fn("hash-table-delete!", HashTable::remove)
+        .register_fn("hash-table-count", HashTable::count)
+        .register_fn("hash-table-keys", HashTable::keys);
     module
 }
 
@@ -169,6 +188,42 @@ pub fn hash_ref(map: &Gc<HashMap<SteelVal, SteelVal>>, key: &SteelVal) -> Result
     }
 }
 
+/// Returns a new hashmap with the given key removed. Performs a functional update,
+/// so the old hash map is still accessible. Removing a key that does not exist
+/// returns the map unchanged.
+///
+/// (hash-remove map key) -> hash?
+///
+/// * map : hash?
+/// * key : any/c
+///
+/// # Examples
+/// ```scheme
+/// > (hash-remove (hash 'a 10 'b 20) 'a)
+///
+/// => #<hashmap {
+///         'b: 20,
+///     }>
+/// ```
+#[function(name = "hash-remove")]
+pub fn hash_remove(map: &mut SteelVal, key: SteelVal) -> Result<SteelVal> {
+    if key.is_hashable() {
+        if let SteelVal::HashMapV(SteelHashMap(ref mut m)) = map {
+            match Gc::get_mut(m) {
+                Some(m) => {
+                    m.remove(&key);
+                    Ok(std::mem::replace(map, SteelVal::Void))
+                }
+                None => Ok(SteelVal::HashMapV(Gc::new(m.without(&key)).into())),
+            }
+        } else {
+            stop!(TypeMismatch => "hash-remove expects a hash map, found: {:?}", map);
+        }
+    } else {
+        stop!(TypeMismatch => "hash key not hashable: {:?}", key)
+    }
+}
+
 /// Gets the `key` from the given `map`. Returns #false if the key does not exist.
 ///
 /// (hash-try-get map key) -> (or any/c #false)
@@ -206,6 +261,24 @@ pub fn hash_length(map: &Gc<HashMap<SteelVal, SteelVal>>) -> usize {
     map.len()
 }
 
+/// Returns the number of entries in the map. This is the same as `hash-length`.
+///
+/// (hash-count map) -> exact-nonnegative-integer?
+///
+/// * map : hash?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (hash-count (hash 'a 10 'b 20)) ;; => 2
+/// > (hash-count (hash-remove (hash 'a 10 'b 20) 'a)) ;; => 1
+/// > (hash-count (hash)) ;; => 0
+/// ```
+#[function(name = "hash-count")]
+pub fn hash_count(map: &Gc<HashMap<SteelVal, SteelVal>>) -> usize {
+    map.len()
+}
+
 /// Checks whether the given map contains the given key. Key must be hashable.
 ///
 /// (hash-contains? map key) -> bool?
@@ -392,6 +465,348 @@ pub fn hm_union(mut hml: &mut SteelVal, mut hmr: &mut SteelVal) -> Result<SteelV
     }
 }
 
+// Whether hashing `value` with `equal?` semantics will succeed. Containers are only
+// hashable when everything inside of them is.
+fn is_equal_hashable(value: &SteelVal) -> bool {
+    match value {
+        SteelVal::BoolV(_)
+        | SteelVal::NumV(_)
+        | SteelVal::IntV(_)
+        | SteelVal::Rational(_)
+        | SteelVal::BigNum(_)
+        | SteelVal::BigRational(_)
+        | SteelVal::Complex(_)
+        | SteelVal::CharV(_)
+        | SteelVal::Void
+        | SteelVal::StringV(_)
+        | SteelVal::SymbolV(_)
+        | SteelVal::FuncV(_)
+        | SteelVal::Closure(_) => true,
+        SteelVal::ListV(l) => l.iter().all(is_equal_hashable),
+        SteelVal::VectorV(v) => v.iter().all(is_equal_hashable),
+        SteelVal::HashMapV(m) => {
+            m.0.iter()
+                .all(|(key, value)| is_equal_hashable(key) && is_equal_hashable(value))
+        }
+        SteelVal::HashSetV(s) => s.0.iter().all(is_equal_hashable),
+        SteelVal::CustomStruct(s) => s.fields.iter().all(is_equal_hashable),
+        _ => false,
+    }
+}
+
+/// Returns an integer hash code for `value` that is consistent with `equal?`:
+/// values that are `equal?` to each other always have the same hash code.
+///
+/// Lambdas hash on their identity, the same way `equal?` compares them. Values that can't be
+/// used as a hash key, such as ports, boxes, mutable vectors and built-in procedures that
+/// need access to the VM, raise an error, including when they're nested inside a list,
+/// vector, hash map or struct.
+///
+/// (equal-hash any/c) -> int?
+///
+/// # Examples
+/// ```scheme
+/// > (= (equal-hash (list 1 2 3)) (equal-hash (list 1 2 3))) ;; => #true
+/// > (= (equal-hash "hello") (equal-hash (string-append "hel" "lo"))) ;; => #true
+/// ```
+#[function(name = "equal-hash")]
+pub fn equal_hash(value: &SteelVal) -> Result<SteelVal> {
+    if !is_equal_hashable(value) {
+        stop!(TypeMismatch => "equal-hash: value is not hashable: {}", value);
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+
+    Ok(SteelVal::IntV(hasher.finish() as isize))
+}
+
+/// Returns the entries of the given hash map as a list of `(key . value)` pairs.
+///
+/// By default the entries come out in the map's internal order, which is fast but
+/// arbitrary. Passing `#true` for `sorted?` sorts the entries by key, so that output
+/// derived from a hash map is reproducible. Keys are grouped by type in the order
+/// booleans, numbers, characters, strings, symbols and then everything else; numbers
+/// sort numerically, characters and strings by code point, and any other keys by their
+/// printed representation.
+///
+/// (hash->list map [sorted?]) -> (listof pair?)
+///
+/// * map : hash?
+/// * sorted? : bool? = #false
+///
+/// # Examples
+/// ```scheme
+/// > (hash->list (hash 'b 2 'a 1) #true) ;; => '((a . 1) (b . 2))
+/// > (hash->list (hash 10 'x "ten" 'y #\t 'z) #true) ;; => '((10 . x) (#\t . z) ("ten" . y))
+/// ```
+#[function(name = "hash->list")]
+pub fn hash_to_list(
+    hashmap: &Gc<HashMap<SteelVal, SteelVal>>,
+    mut sorted: RestArgsIter<'_, &SteelVal>,
+) -> Result<SteelVal> {
+    let sort = match sorted.next() {
+        Some(flag) => flag?.is_truthy(),
+        None => false,
+    };
+
+    if let Some(next) = sorted.next() {
+        stop!(ArityMismatch => "hash->list expected 1 or 2 arguments, got an additional argument {}", next?);
+    }
+
+    let mut entries: Vec<_> = hashmap.iter().collect();
+
+    if sort {
+        entries.sort_by(|(l, _), (r, _)| canonical_key_order(l, r));
+    }
+
+    Ok(SteelVal::ListV(
+        entries
+            .into_iter()
+            .map(|(key, value)| SteelVal::Pair(Gc::new(Pair::cons(key.clone(), value.clone()))))
+            .collect(),
+    ))
+}
+
+fn key_rank(key: &SteelVal) -> u8 {
+    match key {
+        SteelVal::BoolV(_) => 0,
+        SteelVal::IntV(_)
+        | SteelVal::NumV(_)
+        | SteelVal::Rational(_)
+        | SteelVal::BigNum(_)
+        | SteelVal::BigRational(_) => 1,
+        SteelVal::CharV(_) => 2,
+        SteelVal::StringV(_) => 3,
+        SteelVal::SymbolV(_) => 4,
+        _ => 5,
+    }
+}
+
+/// Total order over hash keys used by the sorted mode of `hash->list`.
+fn canonical_key_order(left: &SteelVal, right: &SteelVal) -> Ordering {
+    let by_value = match (left, right) {
+        (SteelVal::BoolV(l), SteelVal::BoolV(r)) => l.cmp(r),
+        (SteelVal::CharV(l), SteelVal::CharV(r)) => l.cmp(r),
+        (SteelVal::StringV(l), SteelVal::StringV(r)) => l.as_str().cmp(r.as_str()),
+        (SteelVal::SymbolV(l), SteelVal::SymbolV(r)) => l.as_str().cmp(r.as_str()),
+        (l, r) if key_rank(l) == 1 && key_rank(r) == 1 => {
+            l.partial_cmp(r).unwrap_or(Ordering::Equal)
+        }
+        (l, r) => key_rank(l).cmp(&key_rank(r)),
+    };
+
+    // Keys that still tie fall back to their printed form, so the result never
+    // depends on the layout of the map
+    by_value.then_with(|| left.to_string().cmp(&right.to_string()))
+}
+
+/// Key wrapper for tables created with `make-eqv-hash`. Two keys are the same
+/// when they are `eqv?`, so structurally equal lists or strings that live in separate
+/// allocations are distinct keys.
+#[derive(Clone)]
+struct EqvKey(SteelVal);
+
+impl PartialEq for EqvKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eqv(&other.0)
+    }
+}
+
+impl Eq for EqvKey {}
+
+impl Hash for EqvKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(&self.0).hash(state);
+
+        // Numbers hash by value, everything else hashes on the identity of its allocation,
+        // mirroring `eqv?`
+        let address = match &self.0 {
+            SteelVal::IntV(i) => return i.hash(state),
+            // `0.0` and `-0.0` are `eqv?` to each other, so they need to hash the same
+            SteelVal::NumV(n) if *n == 0.0 => return 0.0f64.to_bits().hash(state),
+            SteelVal::NumV(n) => return n.to_bits().hash(state),
+            SteelVal::Rational(r) => return r.hash(state),
+            SteelVal::BigNum(n) => return n.hash(state),
+            SteelVal::BigRational(r) => return r.hash(state),
+            SteelVal::Complex(c) => return c.hash(state),
+            SteelVal::BoolV(b) => return b.hash(state),
+            SteelVal::CharV(c) => return c.hash(state),
+            SteelVal::SymbolV(s) => return s.as_str().hash(state),
+            SteelVal::StringV(s) => s.as_ptr() as usize,
+            SteelVal::VectorV(v) => v.0.as_ptr() as usize,
+            // Lists that are `eq?` share their tail, so the address of the last element
+            // identifies them. Empty lists are all the same.
+            SteelVal::ListV(l) => l.last().map(|x| x as *const SteelVal as usize).unwrap_or(0),
+            SteelVal::Closure(c) => c.as_ptr() as usize,
+            SteelVal::FuncV(f) => *f as usize,
+            SteelVal::MutFunc(f) => *f as usize,
+            SteelVal::BuiltIn(f) => *f as usize,
+            SteelVal::BoxedFunction(f) => std::rc::Rc::as_ptr(f) as *const () as usize,
+            SteelVal::FutureFunc(f) => std::rc::Rc::as_ptr(f) as *const () as usize,
+            SteelVal::Custom(c) => c.as_ptr() as usize,
+            SteelVal::CustomStruct(s) => s.as_ptr() as usize,
+            SteelVal::HashMapV(m) => m.0.as_ptr() as usize,
+            SteelVal::HashSetV(m) => m.0.as_ptr() as usize,
+            SteelVal::PortV(p) => std::rc::Rc::as_ptr(&p.port) as *const () as usize,
+            SteelVal::IterV(i) => i.as_ptr() as usize,
+            SteelVal::ReducerV(r) => r.as_ptr() as usize,
+            SteelVal::FutureV(f) => f.as_ptr() as usize,
+            SteelVal::StreamV(s) => s.as_ptr() as usize,
+            SteelVal::Pair(p) => p.as_ptr() as usize,
+            SteelVal::MutableVector(v) => v.as_ptr_usize(),
+            SteelVal::Boxed(b) => b.as_ptr() as usize,
+            SteelVal::HeapAllocated(h) => h.as_ptr_usize(),
+            // Compared with a dedicated check rather than by address, so these only hash
+            // on their type
+            _ => return,
+        };
+
+        address.hash(state);
+    }
+}
+
+enum HashTableStorage {
+    Equal(std::collections::HashMap<SteelVal, SteelVal>),
+    Eqv(std::collections::HashMap<EqvKey, SteelVal>),
+}
+
+/// Mutable hash table, either compared with `equal?` or with `eqv?`.
+///
+/// Equal tables hash the structure of the key, so any hashable value (including lists and
+/// vectors) can be used to look up an entry. Eqv tables only look at the identity of the key,
+/// which is cheaper to hash and accepts any value as a key.
+pub struct HashTable {
+    storage: HashTableStorage,
+}
+
+impl Custom for HashTable {
+    fn gc_visit_children(&self, context: &mut crate::values::closed::MarkAndSweepContext) {
+        for (key, value) in self.entries() {
+            context.push_back(key);
+            context.push_back(value);
+        }
+    }
+}
+
+impl HashTable {
+    /// Creates an empty hash table whose keys are compared with `equal?`.
+    ///
+    /// (make-equal-hash) -> hash-table?
+    ///
+    /// `make-hash` is an alias for this.
+    pub fn equal() -> Self {
+        Self {
+            storage: HashTableStorage::Equal(std::collections::HashMap::new()),
+        }
+    }
+
+    /// Creates an empty hash table whose keys are compared with `eqv?`.
+    ///
+    /// (make-eqv-hash) -> hash-table?
+    pub fn eqv() -> Self {
+        Self {
+            storage: HashTableStorage::Eqv(std::collections::HashMap::new()),
+        }
+    }
+
+    fn entries(&self) -> Vec<(SteelVal, SteelVal)> {
+        match &self.storage {
+            HashTableStorage::Equal(table) => {
+                table.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+            }
+            HashTableStorage::Eqv(table) => table
+                .iter()
+                .map(|(k, v)| (k.0.clone(), v.clone()))
+                .collect(),
+        }
+    }
+
+    fn get(&self, key: &SteelVal) -> Result<Option<&SteelVal>> {
+        match &self.storage {
+            HashTableStorage::Equal(table) => {
+                if !key.is_hashable() {
+                    stop!(TypeMismatch => "hash key not hashable: {}", key);
+                }
+
+                Ok(table.get(key))
+            }
+            HashTableStorage::Eqv(table) => Ok(table.get(&EqvKey(key.clone()))),
+        }
+    }
+
+    /// Associates `key` with `value` in the table, replacing any previous entry.
+    ///
+    /// (hash-table-set! table key value) -> void?
+    pub fn insert(&mut self, key: SteelVal, value: SteelVal) -> Result<()> {
+        match &mut self.storage {
+            HashTableStorage::Equal(table) => {
+                if !key.is_hashable() {
+                    stop!(TypeMismatch => "hash key not hashable: {}", key);
+                }
+
+                table.insert(key, value);
+            }
+            HashTableStorage::Eqv(table) => {
+                table.insert(EqvKey(key), value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the value for `key`, raising an error if the key is not in the table.
+    ///
+    /// (hash-table-ref table key) -> any/c
+    pub fn get_value(&self, key: SteelVal) -> Result<SteelVal> {
+        match self.get(&key)? {
+            Some(value) => Ok(value.clone()),
+            None => stop!(Generic => "key not found in hash table: {}", key),
+        }
+    }
+
+    /// Gets the value for `key`, returning `default` if the key is not in the table.
+    ///
+    /// (hash-table-ref/default table key default) -> any/c
+    pub fn get_or_default(&self, key: SteelVal, default: SteelVal) -> Result<SteelVal> {
+        Ok(self.get(&key)?.cloned().unwrap_or(default))
+    }
+
+    /// (hash-table-contains? table key) -> bool?
+    pub fn contains(&self, key: SteelVal) -> Result<bool> {
+        Ok(self.get(&key)?.is_some())
+    }
+
+    /// Removes `key` from the table if it is present.
+    ///
+    /// (hash-table-delete! table key) -> void?
+    pub fn remove(&mut self, key: SteelVal) {
+        match &mut self.storage {
+            HashTableStorage::Equal(table) => {
+                table.remove(&key);
+            }
+            HashTableStorage::Eqv(table) => {
+                table.remove(&EqvKey(key));
+            }
+        }
+    }
+
+    /// (hash-table-count table) -> int?
+    pub fn count(&self) -> usize {
+        match &self.storage {
+            HashTableStorage::Equal(table) => table.len(),
+            HashTableStorage::Eqv(table) => table.len(),
+        }
+    }
+
+    /// Returns the keys of the table as a list, in no particular order.
+    ///
+    /// (hash-table-keys table) -> list?
+    pub fn keys(&self) -> Vec<SteelVal> {
+        self.entries().into_iter().map(|(key, _)| key).collect()
+    }
+}
+
 #[cfg(test)]
 mod hashmap_tests {
     use super::*;
@@ -662,4 +1077,80 @@ mod hashmap_tests {
 
         assert_eq!(res_vec_string, expected_vec_string);
     }
+
+    #[test]
+    fn equal_hash_matches_for_equal_values() {
+        let pairs = [
+            (IntV(10), IntV(10)),
+            (NumV(1.5), NumV(1.5)),
+            (StringV("hello".into()), StringV("hello".into())),
+            (SymbolV("foo".into()), SymbolV("foo".into())),
+            (
+                ListV(vec![IntV(1), StringV("a".into())].into()),
+                ListV(vec![IntV(1), StringV("a".into())].into()),
+            ),
+        ];
+
+        for (left, right) in pairs {
+            assert_eq!(left, right);
+            assert_eq!(equal_hash(&left).unwrap(), equal_hash(&right).unwrap());
+        }
+    }
+
+    #[test]
+    fn equal_hash_distinguishes_strings_and_symbols() {
+        assert_ne!(
+            equal_hash(&StringV("foo".into())).unwrap(),
+            equal_hash(&SymbolV("foo".into())).unwrap()
+        );
+    }
+
+    #[test]
+    fn equal_hash_rejects_unhashable_values() {
+        let boxed = SteelVal::Boxed(Gc::new(std::cell::RefCell::new(IntV(1))));
+        assert!(equal_hash(&boxed).is_err());
+    }
+
+    #[test]
+    fn equal_hash_rejects_nested_unhashable_values() {
+        let boxed = || SteelVal::Boxed(Gc::new(std::cell::RefCell::new(IntV(1))));
+
+        let in_list = ListV(vec![IntV(1), boxed()].into());
+        let in_vector: SteelVal = im_rc::vector![IntV(1), boxed()].into();
+        let in_map = SteelVal::HashMapV(Gc::new(hashmap! { IntV(1) => boxed() }).into());
+
+        for value in [in_list, in_vector, in_map] {
+            let err = equal_hash(&value).unwrap_err();
+            assert_eq!(err.kind(), crate::rerrs::ErrorKind::TypeMismatch);
+        }
+    }
+
+    #[test]
+    fn sorted_hash_to_list_orders_by_key() {
+        let map = Gc::new(hashmap! {
+            SymbolV("b".into()) => IntV(2),
+            StringV("a".into()) => IntV(1),
+            IntV(10) => IntV(3),
+            IntV(-1) => IntV(4)
+        });
+
+        let sorted = [BoolV(true)];
+        let res = hash_to_list(&map, RestArgsIter::from_slice(&sorted).unwrap()).unwrap();
+
+        let keys: Vec<_> = match res {
+            ListV(entries) => entries
+                .iter()
+                .map(|entry| match entry {
+                    SteelVal::Pair(p) => p.car(),
+                    other => panic!("expected a pair, found: {other}"),
+                })
+                .collect(),
+            other => panic!("expected a list, found: {other}"),
+        };
+
+        assert_eq!(
+            keys,
+            vec![IntV(-1), IntV(10), StringV("a".into()), SymbolV("b".into())]
+        );
+    }
 }
@@ -7,11 +7,13 @@ use crate::{
     values::lists::Pair,
 };
 use crate::{
-    rvals::{IntoSteelVal, Result, SteelVal},
+    rvals::{IntoSteelVal, RestArgsIter, Result, SteelVal},
     steel_vm::vm::VmCore,
 };
 use crate::{stop, throw};
 
+use crate::primitives::nums::index_argument;
+
 use crate::values::lists::List;
 
 use crate::core::utils::{
@@ -84,6 +86,7 @@ pub fn list_module() -> BuiltInModule {
         .register_native_fn_definition(NEW_DEFINITION)
         .register_value_with_doc("cons", crate::primitives::lists::CONS, CONS_DOC)
         .register_native_fn_definition(RANGE_DEFINITION)
+        .register_native_fn_definition(MAKE_LIST_DEFINITION)
         .register_native_fn_definition(LENGTH_DEFINITION)
         .register_value_with_doc("last", crate::primitives::lists::LAST, LAST_DOC)
         .register_native_fn_definition(IS_EMPTY_DEFINITION)
@@ -105,6 +108,8 @@ pub fn list_module() -> BuiltInModule {
         .register_native_fn_definition(THIRD_DEFINITION)
         .register_native_fn_definition(TAKE_DEFINITION)
         .register_native_fn_definition(LIST_TAIL_DEFINITION)
+        .register_native_fn_definition(LIST_COPY_DEFINITION)
+        .register_native_fn_definition(LENGTH_PLUS_DEFINITION)
         .register_native_fn_definition(CDR_IS_NULL_DEFINITION);
 
     module
@@ -210,6 +215,42 @@ pub fn list_tail(list_or_pair: &SteelVal, pos: usize) -> Result<SteelVal> {
     }
 }
 
+/// Returns a copy of the spine of `lst`. The elements themselves are shared with the
+/// original. For an improper list, the pairs are copied and the final non list tail is
+/// kept as is. Anything that isn't a list or a pair is returned unchanged.
+///
+/// (list-copy lst) -> any/c
+///
+/// * lst : any/c
+///
+/// # Examples
+///
+/// ```scheme
+/// > (list-copy '(1 2 3)) ;; => '(1 2 3)
+/// > (list-copy (cons 1 (cons 2 3))) ;; => '(1 2 . 3)
+/// ```
+#[steel_derive::function(name = "list-copy")]
+pub fn list_copy(lst: &SteelVal) -> SteelVal {
+    match lst {
+        SteelVal::ListV(l) => SteelVal::ListV(l.iter().cloned().collect()),
+        SteelVal::Pair(_) => {
+            let mut heads = Vec::new();
+            let mut tail = lst.clone();
+
+            while let SteelVal::Pair(p) = &tail {
+                heads.push(p.car());
+                tail = p.cdr();
+            }
+
+            // Pairs can only end in something that isn't a list, so the tail is shared
+            heads.into_iter().rev().fold(tail, |cdr, car| {
+                SteelVal::Pair(Gc::new(Pair::cons(car, cdr)))
+            })
+        }
+        _ => lst.clone(),
+    }
+}
+
 /// Returns a newly allocated list containing the vs as its elements.
 ///
 /// (list v ...) -> list?
@@ -332,30 +373,70 @@ macro_rules! debug_unreachable {
 //     }
 // }
 
-/// Returns a newly allocated list of the elements in the range (n, m]
+/// Returns a newly allocated list of `k` elements, each of which is `fill`.
+///
+/// (make-list k [fill]) -> list?
+///
+/// * k : int? - a non negative length
+/// * fill : any/c = void - the value of every element
 ///
-/// (range n m) -> (listof int?)
+/// # Examples
+///
+/// ```scheme
+/// > (make-list 3 'a) ;; => '(a a a)
+/// > (make-list 0 'a) ;; => '()
+/// ```
+#[steel_derive::function(name = "make-list")]
+pub fn make_list(k: isize, mut fill: RestArgsIter<'_, &SteelVal>) -> Result<SteelVal> {
+    let value = fill.next();
+
+    if let Some(next) = fill.next() {
+        stop!(ArityMismatch => format!("make-list expected 1 or 2 arguments, got an additional argument {}", next?))
+    }
+
+    if k < 0 {
+        stop!(Generic => "make-list expects a non negative length, found: {}", k);
+    }
+
+    let value = value.transpose()?.cloned().unwrap_or(SteelVal::Void);
+
+    Ok(SteelVal::ListV(
+        std::iter::repeat(value).take(k as usize).collect(),
+    ))
+}
+
+/// Returns a newly allocated list of the elements in the range [n, m), counting by `step`.
+/// The step defaults to 1, and may be negative to count down from `n` to `m`. If `n` is
+/// already past `m` in the direction of the step, the list is empty.
+///
+/// (range n m [step]) -> (listof int?)
 ///
 /// * n : int?
 /// * m : int?
+/// * step : int? (non zero)
 ///
 /// ```scheme
 /// > (range 0 10) ;; => '(0 1 2 3 4 5 6 7 8 9)
+/// > (range 0 10 3) ;; => '(0 3 6 9)
+/// > (range 5 0 -1) ;; => '(5 4 3 2 1)
+/// > (range 5 0) ;; => '()
 /// ```
 #[steel_derive::function(name = "range")]
-fn range(lower: isize, upper: isize) -> Result<SteelVal> {
-    if lower < 0 {
-        stop!(Generic => "range expects a positive integer");
+fn range(lower: isize, upper: isize, mut rest: RestArgsIter<'_, isize>) -> Result<SteelVal> {
+    let step = rest.next().transpose()?.unwrap_or(1);
+
+    if let Some(next) = rest.next() {
+        stop!(ArityMismatch => "range expects 2 or 3 arguments, found an additional argument: {}", next?);
     }
 
-    if upper < 0 {
-        stop!(Generic => "range expects a positive integer");
+    if step == 0 {
+        stop!(ContractViolation => "range expects a non zero step");
     }
 
     Ok(SteelVal::ListV(
-        (lower as usize..upper as usize)
-            .into_iter()
-            .map(|x| SteelVal::IntV(x as isize))
+        std::iter::successors(Some(lower), |x| x.checked_add(step))
+            .take_while(|x| if step > 0 { *x < upper } else { *x > upper })
+            .map(SteelVal::IntV)
             .collect(),
     ))
 }
@@ -376,6 +457,28 @@ fn length(list: &List<SteelVal>) -> usize {
     list.len()
 }
 
+/// Returns the length of `lst` if it is a proper list, and `#false` otherwise, rather
+/// than raising an error like `length`. Lists can't be circular, since pairs are immutable,
+/// so this only returns `#false` for improper lists and values that aren't lists at all.
+///
+/// (length+ lst) -> (or/c int? #false)
+///
+/// * lst : any/c
+///
+/// # Examples
+///
+/// ```scheme
+/// > (length+ (list 10 20 30)) ;; => 3
+/// > (length+ (cons 1 2)) ;; => #false
+/// ```
+#[steel_derive::function(name = "length+")]
+fn length_plus(lst: &SteelVal) -> SteelVal {
+    match lst {
+        SteelVal::ListV(l) => SteelVal::IntV(l.len() as isize),
+        _ => SteelVal::BoolV(false),
+    }
+}
+
 pub(crate) const REVERSE_DOC: DocTemplate<'static> = DocTemplate {
     signature: "(reverse l) -> list?",
     params: &["l : list?"],
@@ -627,7 +730,7 @@ pub fn try_list_ref(args: &[SteelVal]) -> Result<SteelVal> {
 /// (list-ref lst index) -> list?
 ///
 /// * lst : list?
-/// * index : (and/c int? positive?)
+/// * index : exact-nonnegative-integer?
 ///
 /// # Examples
 /// ```scheme
@@ -641,12 +744,10 @@ pub fn try_list_ref(args: &[SteelVal]) -> Result<SteelVal> {
 ///   │  ^^^^^^^^ out of bounds index in list-ref - list length: 4, index: 10
 /// ```
 #[steel_derive::function(name = "list-ref", constant = true)]
-pub fn list_ref(list: &List<SteelVal>, index: isize) -> Result<SteelVal> {
-    if index < 0 {
-        stop!(Generic => "list-ref expects a positive integer, found: {}", index);
-    }
+pub fn list_ref(list: &List<SteelVal>, index: &SteelVal) -> Result<SteelVal> {
+    let index = index_argument("list-ref", index)?;
 
-    list.get(index as usize)
+    list.get(index)
         .cloned()
         .ok_or_else(throw!(Generic => format!("out of bounds index in list-ref - list length: {}, index: {}", list.len(), index)))
 }
@@ -811,9 +912,10 @@ mod list_operation_tests {
     #[test]
     fn range_test_arity_too_many() {
         let args = [
-            SteelVal::NumV(1.0),
-            SteelVal::NumV(2.0),
-            SteelVal::NumV(3.0),
+            SteelVal::IntV(1),
+            SteelVal::IntV(2),
+            SteelVal::IntV(3),
+            SteelVal::IntV(4),
         ];
         let res = steel_range(&args);
         let expected = ErrorKind::ArityMismatch;
@@ -828,4 +930,128 @@ mod list_operation_tests {
             SteelVal::ListV(vec![SteelVal::IntV(0), SteelVal::IntV(1), SteelVal::IntV(2)].into());
         assert_eq!(res.unwrap(), expected);
     }
+
+    #[test]
+    fn range_test_descending_step() {
+        let args = [SteelVal::IntV(3), SteelVal::IntV(0), SteelVal::IntV(-1)];
+        let res = steel_range(&args);
+        let expected =
+            SteelVal::ListV(vec![SteelVal::IntV(3), SteelVal::IntV(2), SteelVal::IntV(1)].into());
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn range_test_empty_when_past_the_end() {
+        let args = [SteelVal::IntV(5), SteelVal::IntV(0)];
+        let res = steel_range(&args);
+        assert_eq!(res.unwrap(), SteelVal::ListV(List::new()));
+    }
+
+    #[test]
+    fn range_test_zero_step() {
+        let args = [SteelVal::IntV(0), SteelVal::IntV(5), SteelVal::IntV(0)];
+        let res = steel_range(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ContractViolation);
+    }
+
+    #[test]
+    fn make_list_with_fill() {
+        let args = [SteelVal::IntV(3), SteelVal::SymbolV("a".into())];
+        let res = steel_make_list(&args);
+
+        let expected = SteelVal::ListV(
+            vec![
+                SteelVal::SymbolV("a".into()),
+                SteelVal::SymbolV("a".into()),
+                SteelVal::SymbolV("a".into()),
+            ]
+            .into(),
+        );
+
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn make_list_default_fill_is_void() {
+        let args = [SteelVal::IntV(2)];
+        let res = steel_make_list(&args);
+
+        let expected = SteelVal::ListV(vec![SteelVal::Void, SteelVal::Void].into());
+
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn make_list_zero_length() {
+        let args = [SteelVal::IntV(0), SteelVal::IntV(10)];
+        let res = steel_make_list(&args);
+
+        assert_eq!(res.unwrap(), SteelVal::ListV(List::new()));
+    }
+
+    #[test]
+    fn make_list_negative_length() {
+        let args = [SteelVal::IntV(-1), SteelVal::IntV(10)];
+        let res = steel_make_list(&args);
+
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Generic);
+    }
+
+    #[test]
+    fn list_copy_proper_list() {
+        let original = SteelVal::ListV(vec![SteelVal::IntV(1), SteelVal::IntV(2)].into());
+        let copy = list_copy(&original);
+
+        assert_eq!(copy, original);
+        assert!(!copy.ptr_eq(&original));
+    }
+
+    #[test]
+    fn list_copy_improper_list() {
+        let tail = SteelVal::IntV(3);
+        let original = SteelVal::Pair(Gc::new(Pair::cons(
+            SteelVal::IntV(1),
+            SteelVal::Pair(Gc::new(Pair::cons(SteelVal::IntV(2), tail.clone()))),
+        )));
+
+        let copy = list_copy(&original);
+        assert!(!copy.ptr_eq(&original));
+
+        let first = copy.pair().unwrap();
+        assert_eq!(first.car(), SteelVal::IntV(1));
+
+        let second = first.cdr();
+        let second = second.pair().unwrap();
+        assert_eq!(second.car(), SteelVal::IntV(2));
+        assert_eq!(second.cdr(), tail);
+    }
+
+    #[test]
+    fn list_copy_non_list() {
+        assert_eq!(list_copy(&SteelVal::IntV(5)), SteelVal::IntV(5));
+    }
+
+    #[test]
+    fn list_to_string_keeps_control_and_non_bmp_chars() {
+        let chars = ['\t', 'a', '\n', '\u{1F980}', '\0'];
+        let list = chars
+            .iter()
+            .copied()
+            .map(SteelVal::CharV)
+            .collect::<List<_>>();
+
+        assert_eq!(
+            list_to_string(&list).unwrap(),
+            SteelVal::StringV("\ta\n\u{1F980}\0".into())
+        );
+    }
+
+    #[test]
+    fn length_plus_of_proper_and_improper_lists() {
+        let proper = SteelVal::ListV(vec![SteelVal::IntV(1), SteelVal::IntV(2)].into());
+        assert_eq!(length_plus(&proper), SteelVal::IntV(2));
+
+        let improper = SteelVal::Pair(Gc::new(Pair::cons(SteelVal::IntV(1), SteelVal::IntV(2))));
+        assert_eq!(length_plus(&improper), SteelVal::BoolV(false));
+    }
 }
@@ -68,13 +68,13 @@ impl MetaOperations {
 
     pub fn assert_truthy() -> SteelVal {
         SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
-            if args.len() != 1 {
-                stop!(ArityMismatch => "assert takes one argument")
-            }
-            if let SteelVal::BoolV(true) = &args[0] {
-                Ok(SteelVal::Void)
-            } else {
-                panic!("Value given not true!")
+            // The optional second argument is the asserted expression, which the `assert!`
+            // macro passes along so that failures can point at what went wrong
+            match args {
+                [SteelVal::BoolV(true)] | [SteelVal::BoolV(true), _] => Ok(SteelVal::Void),
+                [value] => stop!(Generic => "assertion failed: expected #true, found: {}", value),
+                [_, form] => stop!(Generic => "assertion failed: {}", form),
+                _ => stop!(ArityMismatch => "assert! takes one argument"),
             }
         })
     }
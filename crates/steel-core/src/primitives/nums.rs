@@ -1,9 +1,60 @@
 use crate::rvals::{IntoSteelVal, Result, SteelComplex, SteelVal};
 use crate::steel_vm::primitives::{numberp, realp};
 use crate::stop;
-use num::{BigInt, BigRational, CheckedAdd, CheckedMul, Integer, Rational32, ToPrimitive};
+use num::integer::Roots;
+use num::{BigInt, BigRational, CheckedAdd, CheckedMul, Integer, Rational32, Signed, ToPrimitive};
 use std::ops::Neg;
 
+/// Converts `n` to the nearest `f64`. Integers beyond the range of an `f64`
+/// become positive or negative infinity.
+pub(crate) fn bignum_to_f64(n: &BigInt) -> f64 {
+    n.to_f64().unwrap_or(if n.is_negative() {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    })
+}
+
+/// Converts `n` to the nearest `f64`, falling back to dividing the converted
+/// numerator and denominator if the ratio can't be converted directly.
+pub(crate) fn big_rational_to_f64(n: &BigRational) -> f64 {
+    n.to_f64()
+        .unwrap_or_else(|| bignum_to_f64(n.numer()) / bignum_to_f64(n.denom()))
+}
+
+/// Returns the exact value of `n` by decomposing it into its IEEE 754 mantissa and
+/// exponent, or `None` if `n` is infinite or NaN.
+pub(crate) fn f64_to_exact(n: f64) -> Option<BigRational> {
+    if !n.is_finite() {
+        return None;
+    }
+
+    let bits = n.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+
+    // Subnormals don't have the implicit leading bit, and share the smallest exponent
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    let mut mantissa = BigInt::from(mantissa);
+    if bits >> 63 == 1 {
+        mantissa = -mantissa;
+    }
+
+    if exponent >= 0 {
+        Some(BigRational::from_integer(mantissa << exponent as usize))
+    } else {
+        Some(BigRational::new(
+            mantissa,
+            BigInt::from(1) << (-exponent) as usize,
+        ))
+    }
+}
+
 fn ensure_args_are_numbers(op: &str, args: &[SteelVal]) -> Result<()> {
     for arg in args {
         if !numberp(arg) {
@@ -24,14 +75,14 @@ fn multiply_two(x: &SteelVal, y: &SteelVal) -> Result<SteelVal> {
             (x * *y as f64).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::BigNum(y)) | (SteelVal::BigNum(y), SteelVal::NumV(x)) => {
-            (x * y.to_f64().unwrap()).into_steelval()
+            (x * bignum_to_f64(y)).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::Rational(y)) | (SteelVal::Rational(y), SteelVal::NumV(x)) => {
             (x * y.to_f64().unwrap()).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::BigRational(y))
         | (SteelVal::BigRational(y), SteelVal::NumV(x)) => {
-            (x * y.to_f64().unwrap()).into_steelval()
+            (x * big_rational_to_f64(y)).into_steelval()
         }
         (SteelVal::IntV(x), SteelVal::IntV(y)) => match x.checked_mul(y) {
             Some(res) => res.into_steelval(),
@@ -126,8 +177,138 @@ pub fn multiply_primitive(args: &[SteelVal]) -> Result<SteelVal> {
     multiply_primitive_impl(args)
 }
 
-pub fn quotient(l: isize, r: isize) -> isize {
-    l / r
+// Widens an exact integer to a `BigInt`, used by the slow paths of the integer division functions.
+fn exact_integer_to_bigint(name: &str, value: &SteelVal) -> Result<BigInt> {
+    match value {
+        SteelVal::IntV(i) => Ok(BigInt::from(*i)),
+        SteelVal::BigNum(n) => Ok(n.as_ref().clone()),
+        _ => stop!(TypeMismatch => "{} expects an integer, found: {}", name, value),
+    }
+}
+
+// Shared implementation of `quotient`, `remainder` and `modulo`. Two fixnums take the fast
+// path, falling back to `BigInt` arithmetic on overflow (e.g. dividing `isize::MIN` by -1)
+// or when either argument is already a `BigNum`.
+fn integer_division(
+    name: &str,
+    left: &SteelVal,
+    right: &SteelVal,
+    fast: fn(isize, isize) -> Option<isize>,
+    slow: fn(&BigInt, &BigInt) -> BigInt,
+) -> Result<SteelVal> {
+    if matches!(right, SteelVal::IntV(0)) {
+        stop!(Generic => "{}: division by zero", name);
+    }
+
+    if let (SteelVal::IntV(l), SteelVal::IntV(r)) = (left, right) {
+        if let Some(result) = fast(*l, *r) {
+            return Ok(SteelVal::IntV(result));
+        }
+    }
+
+    let l = exact_integer_to_bigint(name, left)?;
+    let r = exact_integer_to_bigint(name, right)?;
+
+    slow(&l, &r).into_steelval()
+}
+
+/// Returns the quotient of dividing `n` by `m`, truncating towards zero.
+///
+/// (quotient n m) -> integer?
+///
+/// * n : integer?
+/// * m : integer?
+///
+/// # Examples
+/// ```scheme
+/// > (quotient 13 4) ;; => 3
+/// > (quotient -13 4) ;; => -3
+/// ```
+#[steel_derive::function(name = "quotient", constant = true)]
+pub fn quotient(left: &SteelVal, right: &SteelVal) -> Result<SteelVal> {
+    integer_division("quotient", left, right, isize::checked_div, |l, r| l / r)
+}
+
+/// Returns the remainder of dividing `n` by `m`. The result has the same sign as `n`.
+///
+/// (remainder n m) -> integer?
+///
+/// * n : integer?
+/// * m : integer?
+///
+/// # Examples
+/// ```scheme
+/// > (remainder 13 4) ;; => 1
+/// > (remainder -13 4) ;; => -1
+/// ```
+#[steel_derive::function(name = "remainder", constant = true)]
+pub fn remainder(left: &SteelVal, right: &SteelVal) -> Result<SteelVal> {
+    integer_division("remainder", left, right, isize::checked_rem, |l, r| l % r)
+}
+
+/// Returns `n` modulo `m`. The result has the same sign as `m`.
+///
+/// (modulo n m) -> integer?
+///
+/// * n : integer?
+/// * m : integer?
+///
+/// # Examples
+/// ```scheme
+/// > (modulo 13 4) ;; => 1
+/// > (modulo -13 4) ;; => 3
+/// > (modulo 13 -4) ;; => -3
+/// ```
+#[steel_derive::function(name = "modulo", constant = true)]
+pub fn modulo(left: &SteelVal, right: &SteelVal) -> Result<SteelVal> {
+    integer_division(
+        "modulo",
+        left,
+        right,
+        |l, r| {
+            l.checked_rem(r).map(|m| {
+                if m != 0 && (m < 0) != (r < 0) {
+                    m + r
+                } else {
+                    m
+                }
+            })
+        },
+        |l, r| l.mod_floor(r),
+    )
+}
+
+/// Returns two values, the largest integer `s` such that `s * s` is no greater than `n`,
+/// and the remainder `n - s * s`. The result is computed with integer arithmetic, so it
+/// stays exact for values that don't fit in a float.
+///
+/// (exact-integer-sqrt n) -> (values integer? integer?)
+///
+/// * n : (and/c integer? (>=/c 0))
+///
+/// # Examples
+/// ```scheme
+/// > (exact-integer-sqrt 16) ;; => '(4 0)
+/// > (exact-integer-sqrt 17) ;; => '(4 1)
+/// ```
+#[steel_derive::function(name = "exact-integer-sqrt", constant = true)]
+pub fn exact_integer_sqrt(number: &SteelVal) -> Result<SteelVal> {
+    let (root, rest) = match number {
+        SteelVal::IntV(n) if *n >= 0 => {
+            let root = n.sqrt();
+            (SteelVal::IntV(root), SteelVal::IntV(n - root * root))
+        }
+        SteelVal::BigNum(n) if n.sign() != num::bigint::Sign::Minus => {
+            let root = n.sqrt();
+            let rest = n.as_ref() - &root * &root;
+            (root.into_steelval()?, rest.into_steelval()?)
+        }
+        _ => {
+            stop!(TypeMismatch => "exact-integer-sqrt expects a non-negative integer, found: {}", number)
+        }
+    };
+
+    Ok(SteelVal::ListV(vec![root, rest].into()))
 }
 
 #[steel_derive::native(name = "/", constant = true, arity = "AtLeast(1)")]
@@ -218,14 +399,14 @@ pub fn add_two(x: &SteelVal, y: &SteelVal) -> Result<SteelVal> {
             (x + *y as f64).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::BigNum(y)) | (SteelVal::BigNum(y), SteelVal::NumV(x)) => {
-            (x + y.to_f64().unwrap()).into_steelval()
+            (x + bignum_to_f64(y)).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::Rational(y)) | (SteelVal::Rational(y), SteelVal::NumV(x)) => {
             (x + y.to_f64().unwrap()).into_steelval()
         }
         (SteelVal::NumV(x), SteelVal::BigRational(y))
         | (SteelVal::BigRational(y), SteelVal::NumV(x)) => {
-            (x + y.to_f64().unwrap()).into_steelval()
+            (x + big_rational_to_f64(y)).into_steelval()
         }
         // Cases that interact with `Rational`.
         (SteelVal::Rational(x), SteelVal::Rational(y)) => (x + y).into_steelval(),
@@ -348,6 +529,42 @@ pub fn inexactp(value: &SteelVal) -> bool {
     }
 }
 
+/// Checks if the given value is an exact integer that is zero or greater, which is what
+/// the indexed primitives such as `vector-ref` and `list-ref` expect.
+///
+/// (exact-nonnegative-integer? any/c) -> bool?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (exact-nonnegative-integer? 0) ;; => #true
+/// > (exact-nonnegative-integer? -1) ;; => #false
+/// > (exact-nonnegative-integer? 1.0) ;; => #false
+/// ```
+#[steel_derive::function(name = "exact-nonnegative-integer?", constant = true)]
+pub fn exact_nonnegative_integerp(value: &SteelVal) -> bool {
+    match value {
+        SteelVal::IntV(i) => *i >= 0,
+        SteelVal::BigNum(b) => !b.is_negative(),
+        _ => false,
+    }
+}
+
+/// Converts an index argument given to `function` into a `usize`, raising a contract
+/// violation for anything that isn't an exact nonnegative integer.
+pub(crate) fn index_argument(function: &str, value: &SteelVal) -> Result<usize> {
+    match value {
+        SteelVal::IntV(i) if *i >= 0 => Ok(*i as usize),
+        // Nothing can be indexed this far, so report it as out of bounds
+        SteelVal::BigNum(b) if !b.is_negative() => {
+            stop!(Generic => "{}: index out of bounds: {}", function, value)
+        }
+        _ => {
+            stop!(ContractViolation => "{} expects an index that is an exact nonnegative integer, found: {}", function, value)
+        }
+    }
+}
+
 pub struct NumOperations {}
 impl NumOperations {
     pub fn arithmetic_shift() -> SteelVal {
@@ -578,4 +795,151 @@ mod num_op_tests {
         let expected = IntV(8);
         assert_eq!(got, expected);
     }
+
+    #[test]
+    fn integer_division_signs() {
+        assert_eq!(quotient(&IntV(-13), &IntV(4)).unwrap(), IntV(-3));
+        assert_eq!(remainder(&IntV(-13), &IntV(4)).unwrap(), IntV(-1));
+        assert_eq!(modulo(&IntV(-13), &IntV(4)).unwrap(), IntV(3));
+        assert_eq!(modulo(&IntV(13), &IntV(-4)).unwrap(), IntV(-3));
+        assert_eq!(modulo(&IntV(-13), &IntV(-4)).unwrap(), IntV(-1));
+        assert_eq!(modulo(&IntV(12), &IntV(-4)).unwrap(), IntV(0));
+    }
+
+    #[test]
+    fn integer_division_by_zero_errors() {
+        assert!(quotient(&IntV(1), &IntV(0)).is_err());
+        assert!(remainder(&IntV(1), &IntV(0)).is_err());
+        assert!(modulo(&IntV(1), &IntV(0)).is_err());
+    }
+
+    #[test]
+    fn quotient_overflow_promotes_to_bignum() {
+        let got = quotient(&IntV(isize::MIN), &IntV(-1)).unwrap();
+        let expected = BigNum(Gc::new(-BigInt::from(isize::MIN)));
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn exact_integer_sqrt_perfect_square() {
+        let got = exact_integer_sqrt(&IntV(16)).unwrap();
+        assert_eq!(got, ListV(vec![IntV(4), IntV(0)].into()));
+    }
+
+    #[test]
+    fn exact_integer_sqrt_with_remainder() {
+        let got = exact_integer_sqrt(&IntV(17)).unwrap();
+        assert_eq!(got, ListV(vec![IntV(4), IntV(1)].into()));
+    }
+
+    #[test]
+    fn exact_integer_sqrt_large_value() {
+        // (2^53 + 1)^2 isn't representable as a float, so a float sqrt would lose the last digit
+        let root = BigInt::from(9007199254740993_i64);
+        let square = &root * &root + BigInt::from(5);
+        let got = exact_integer_sqrt(&BigNum(Gc::new(square))).unwrap();
+        assert_eq!(got, ListV(vec![IntV(9007199254740993), IntV(5)].into()));
+    }
+
+    #[test]
+    fn exact_integer_sqrt_negative_errors() {
+        assert!(exact_integer_sqrt(&IntV(-1)).is_err());
+    }
+
+    #[test]
+    fn bignum_to_f64_just_beyond_isize() {
+        let big = BigInt::from(isize::MAX) * 10;
+        assert_eq!(bignum_to_f64(&big), isize::MAX as f64 * 10.0);
+        assert_eq!(bignum_to_f64(&-big), isize::MIN as f64 * 10.0);
+    }
+
+    #[test]
+    fn bignum_to_f64_beyond_f64_is_infinite() {
+        let huge = BigInt::from(10).pow(400);
+        assert_eq!(bignum_to_f64(&huge), f64::INFINITY);
+        assert_eq!(bignum_to_f64(&-huge), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn big_rational_to_f64_large_terms() {
+        let ratio = BigRational::new(BigInt::from(10).pow(400), BigInt::from(10).pow(399) * 4);
+        assert_eq!(big_rational_to_f64(&ratio), 2.5);
+    }
+
+    #[test]
+    fn float_arithmetic_with_huge_bignum() {
+        let huge = BigNum(Gc::new(BigInt::from(10).pow(400)));
+
+        assert_eq!(
+            add_primitive(&[NumV(1.5), huge.clone()]).unwrap(),
+            NumV(f64::INFINITY)
+        );
+        assert_eq!(
+            multiply_primitive(&[NumV(-1.5), huge]).unwrap(),
+            NumV(f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn f64_to_exact_representable_fractions() {
+        assert_eq!(
+            f64_to_exact(0.5).unwrap(),
+            BigRational::new(BigInt::from(1), BigInt::from(2))
+        );
+        assert_eq!(
+            f64_to_exact(-0.75).unwrap(),
+            BigRational::new(BigInt::from(-3), BigInt::from(4))
+        );
+        assert_eq!(
+            f64_to_exact(3.0).unwrap(),
+            BigRational::from_integer(BigInt::from(3))
+        );
+        assert_eq!(
+            f64_to_exact(0.0).unwrap(),
+            BigRational::from_integer(BigInt::from(0))
+        );
+    }
+
+    #[test]
+    fn f64_to_exact_is_the_value_of_the_float() {
+        let tenth = f64_to_exact(0.1).unwrap();
+        assert_eq!(tenth.denom(), &(BigInt::from(1) << 55));
+        assert_eq!(tenth.numer(), &BigInt::from(3602879701896397u64));
+        assert_eq!(big_rational_to_f64(&tenth), 0.1);
+
+        let tiny = f64_to_exact(f64::MIN_POSITIVE / 4.0).unwrap();
+        assert_eq!(big_rational_to_f64(&tiny), f64::MIN_POSITIVE / 4.0);
+
+        let huge = f64_to_exact(1e300).unwrap();
+        assert!(huge.is_integer());
+        assert_eq!(big_rational_to_f64(&huge), 1e300);
+    }
+
+    #[test]
+    fn f64_to_exact_rejects_non_finite() {
+        assert!(f64_to_exact(f64::INFINITY).is_none());
+        assert!(f64_to_exact(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn exact_nonnegative_integer_test() {
+        assert!(exact_nonnegative_integerp(&IntV(0)));
+        assert!(exact_nonnegative_integerp(&IntV(10)));
+        assert!(!exact_nonnegative_integerp(&IntV(-1)));
+        assert!(!exact_nonnegative_integerp(&NumV(1.0)));
+        assert!(!exact_nonnegative_integerp(&Rational(Rational32::new(
+            1, 2
+        ))));
+        assert!(!exact_nonnegative_integerp(&SteelVal::StringV("1".into())));
+    }
+
+    #[test]
+    fn index_argument_test() {
+        assert_eq!(index_argument("test", &IntV(3)).unwrap(), 3);
+
+        for bad in [IntV(-1), NumV(1.0), NumV(-2.5)] {
+            let err = index_argument("test", &bad).unwrap_err();
+            assert_eq!(err.kind(), crate::rerrs::ErrorKind::ContractViolation);
+        }
+    }
 }
@@ -18,6 +18,11 @@ pub fn port_module() -> BuiltInModule {
         .register_native_fn_definition(OPEN_INPUT_FILE_DEFINITION)
         .register_native_fn_definition(OPEN_OUTPUT_FILE_DEFINITION)
         .register_native_fn_definition(OPEN_OUTPUT_STRING_DEFINITION)
+        .register_native_fn_definition(OPEN_INPUT_STRING_DEFINITION)
+        .register_native_fn_definition(READ_CHAR_DEFINITION)
+        .register_native_fn_definition(PEEK_CHAR_DEFINITION)
+        .register_native_fn_definition(EOF_OBJECT_DEFINITION)
+        .register_native_fn_definition(IS_EOF_OBJECT_DEFINITION)
         .register_native_fn_definition(WRITE_LINE_DEFINITION)
         .register_native_fn_definition(WRITE_STRING_DEFINITION)
         .register_native_fn_definition(WRITE_DEFINITION)
@@ -94,6 +99,73 @@ pub fn open_output_string() -> SteelVal {
     SteelVal::PortV(SteelPort::new_output_port())
 }
 
+/// Creates an input port that reads from the given string
+///
+/// (open-input-string string?) -> input-port?
+///
+/// # Examples
+/// ```scheme
+/// > (read-char (open-input-string "hello")) ;; => #\h
+/// ```
+#[function(name = "open-input-string")]
+pub fn open_input_string(value: &SteelString) -> SteelVal {
+    SteelVal::PortV(SteelPort::new_input_port_string(value.as_str().to_string()))
+}
+
+fn char_or_eof(value: Option<char>) -> SteelVal {
+    match value {
+        Some(c) => SteelVal::CharV(c),
+        None => eof_object(),
+    }
+}
+
+/// Reads the next character from the port, or returns the eof object if the port is exhausted
+///
+/// (raw-read-char port) -> (or char? eof-object?)
+///
+/// * port : input-port?
+#[function(name = "raw-read-char")]
+pub fn read_char(port: &SteelPort) -> Result<SteelVal> {
+    port.read_char().map(char_or_eof)
+}
+
+/// Returns the next character from the port without consuming it, so the following
+/// read will return the same character. Returns the eof object if the port is exhausted
+///
+/// (raw-peek-char port) -> (or char? eof-object?)
+///
+/// * port : input-port?
+#[function(name = "raw-peek-char")]
+pub fn peek_char(port: &SteelPort) -> Result<SteelVal> {
+    port.peek_char().map(char_or_eof)
+}
+
+/// Returns the value used to signal the end of an input port
+///
+/// (eof-object) -> eof-object?
+#[function(name = "eof-object")]
+pub fn eof_object() -> SteelVal {
+    SteelVal::SymbolV(EOF_OBJECT.with(|x| x.clone()))
+}
+
+/// Checks if the given value is the eof object
+///
+/// (eof-object? any/c) -> bool?
+///
+/// # Examples
+/// ```scheme
+/// > (eof-object? (read-char (open-input-string ""))) ;; => #true
+/// > (eof-object? #\a) ;; => #false
+/// ```
+#[function(name = "eof-object?")]
+pub fn is_eof_object(value: &SteelVal) -> bool {
+    if let SteelVal::SymbolV(s) = value {
+        EOF_OBJECT.with(|eof| eof == s)
+    } else {
+        false
+    }
+}
+
 /// Takes a port and reads the entire content into a string
 ///
 /// (read-port-to-string port) -> string?
@@ -149,7 +221,7 @@ pub fn read_line_to_string(port: &SteelPort) -> Result<SteelVal> {
 
     if let Ok((size, result)) = res {
         if size == 0 {
-            Ok(SteelVal::SymbolV(EOF_OBJECT.with(|x| x.clone())))
+            Ok(eof_object())
         } else {
             Ok(SteelVal::StringV(result.into()))
         }
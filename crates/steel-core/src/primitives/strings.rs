@@ -1,8 +1,10 @@
 use crate::values::lists::List;
 
-use crate::rvals::{RestArgsIter, Result, SteelString, SteelVal};
+use crate::primitives::nums::index_argument;
+use crate::rvals::{IntoSteelVal, RestArgsIter, Result, SteelString, SteelVal};
 use crate::steel_vm::builtin::BuiltInModule;
 use crate::steel_vm::register_fn::RegisterFn;
+use crate::steel_vm::vm::{VmContext, VmCore};
 use crate::stop;
 
 use steel_derive::{function, native};
@@ -11,6 +13,59 @@ fn char_upcase(c: char) -> char {
     c.to_ascii_uppercase()
 }
 
+/// Characters whose simple case folding differs from their lowercase mapping.
+fn fold_special(c: char) -> Option<char> {
+    match c {
+        '\u{00B5}' => Some('\u{03BC}'), // micro sign -> greek small mu
+        '\u{017F}' => Some('s'),        // latin small long s
+        '\u{0345}' => Some('\u{03B9}'), // combining greek ypogegrammeni
+        '\u{03C2}' => Some('\u{03C3}'), // greek small final sigma
+        '\u{03D0}' => Some('\u{03B2}'), // greek beta symbol
+        '\u{03D1}' => Some('\u{03B8}'), // greek theta symbol
+        '\u{03D5}' => Some('\u{03C6}'), // greek phi symbol
+        '\u{03D6}' => Some('\u{03C0}'), // greek pi symbol
+        '\u{03F0}' => Some('\u{03BA}'), // greek kappa symbol
+        '\u{03F1}' => Some('\u{03C1}'), // greek rho symbol
+        '\u{03F5}' => Some('\u{03B5}'), // greek lunate epsilon symbol
+        '\u{1E9B}' => Some('\u{1E61}'), // latin small long s with dot above
+        '\u{1E9E}' => Some('\u{00DF}'), // latin capital sharp s
+        '\u{1FBE}' => Some('\u{03B9}'), // greek prosgegrammeni
+        _ => None,
+    }
+}
+
+/// Simple case folding, mapping a single character to a single character.
+/// Characters that only fold to multiple characters are left alone.
+fn char_foldcase_impl(c: char) -> char {
+    if let Some(folded) = fold_special(c) {
+        return folded;
+    }
+
+    let mut lower = c.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Full case folding, which is what the case insensitive string comparisons use.
+fn string_foldcase_impl(value: &str) -> String {
+    let mut folded = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\u{00DF}' | '\u{1E9E}' => folded.push_str("ss"),
+            _ => match fold_special(c) {
+                Some(f) => folded.push(f),
+                None => folded.extend(c.to_lowercase()),
+            },
+        }
+    }
+
+    folded
+}
+
 /// # steel/strings
 ///
 /// Strings in Steel are immutable, fixed length arrays of characters. They are heap allocated, and
@@ -39,6 +94,10 @@ pub fn string_module() -> BuiltInModule {
         .register_native_fn_definition(ENDS_WITH_DEFINITION)
         .register_native_fn_definition(TRIM_END_MATCHES_DEFINITION)
         .register_native_fn_definition(TRIM_START_MATCHES_DEFINITION)
+        .register_value("string-trim", string_trim())
+        .register_value("string-trim-left", string_trim_left())
+        .register_value("string-trim-right", string_trim_right())
+        .register_value("string-split", string_split())
         .register_native_fn_definition(STRING_REF_DEFINITION)
         .register_native_fn_definition(SUBSTRING_DEFINITION)
         .register_native_fn_definition(MAKE_STRING_DEFINITION)
@@ -54,11 +113,21 @@ pub fn string_module() -> BuiltInModule {
         .register_native_fn_definition(STRING_CI_GREATER_THAN_EQUAL_TO_DEFINITION)
         .register_native_fn_definition(STRING_CONSTRUCTOR_DEFINITION)
         .register_native_fn_definition(STRING_TO_NUMBER_DEFINITION)
+        .register_native_fn_definition(IS_STRING_NUMBER_DEFINITION)
         .register_native_fn_definition(NUMBER_TO_STRING_DEFINITION)
         .register_native_fn_definition(REPLACE_DEFINITION)
+        .register_native_fn_definition(STRING_PAD_LEFT_DEFINITION)
+        .register_native_fn_definition(STRING_PAD_RIGHT_DEFINITION)
+        .register_native_fn_definition(FORMAT_DEFINITION)
+        .register_native_fn_definition(STRING_FOLDCASE_DEFINITION)
+        .register_native_fn_definition(CHAR_FOLDCASE_DEFINITION)
+        .register_native_fn_definition(CHAR_CI_EQUALS_DEFINITION)
+        .register_native_fn_definition(DIGIT_VALUE_DEFINITION)
+        .register_native_fn_definition(CHAR_RANGE_DEFINITION)
         .register_fn("char-upcase", char_upcase)
         .register_fn("char-whitespace?", char::is_whitespace)
         .register_fn("char-digit?", |c: char| char::is_digit(c, 10))
+        .register_fn("char-numeric?", char::is_numeric)
         .register_fn("char->number", |c: char| char::to_digit(c, 10))
         .register_native_fn_definition(CHAR_EQUALS_DEFINITION);
     module
@@ -73,6 +142,94 @@ pub fn char_equals(left: char, right: char) -> bool {
     left == right
 }
 
+/// Checks if two characters are equal after applying case folding
+///
+/// (char-ci=? char? char?) -> bool?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (char-ci=? #\a #\A) ;; => #true
+/// > (char-ci=? #\σ #\ς) ;; => #true
+/// ```
+#[function(name = "char-ci=?", constant = true)]
+pub fn char_ci_equals(left: char, right: char) -> bool {
+    char_foldcase_impl(left) == char_foldcase_impl(right)
+}
+
+/// Applies simple Unicode case folding to the character. This is the form used
+/// for case insensitive comparisons, and differs from downcasing for a handful of characters.
+///
+/// (char-foldcase char?) -> char?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (char-foldcase #\A) ;; => #\a
+/// > (char-foldcase #\ς) ;; => #\σ
+/// ```
+#[function(name = "char-foldcase", constant = true)]
+pub fn char_foldcase(value: char) -> char {
+    char_foldcase_impl(value)
+}
+
+// The zero of every run of decimal digits (general category Nd) in Unicode. Each run is
+// ten consecutive code points, starting at zero and counting up to nine.
+const DECIMAL_DIGIT_ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+fn digit_value_impl(c: char) -> Option<u32> {
+    let c = c as u32;
+
+    DECIMAL_DIGIT_ZEROS
+        .iter()
+        .find(|zero| (**zero..**zero + 10).contains(&c))
+        .map(|zero| c - zero)
+}
+
+/// Returns the numeric value of a decimal digit character, or `#false` if the
+/// character is not a decimal digit. Decimal digits from any script are accepted.
+///
+/// (digit-value char?) -> (or/c int? #false)
+///
+/// # Examples
+///
+/// ```scheme
+/// > (digit-value #\3) ;; => 3
+/// > (digit-value #\٤) ;; => 4
+/// > (digit-value #\a) ;; => #false
+/// ```
+#[function(name = "digit-value", constant = true)]
+pub fn digit_value(value: char) -> Option<u32> {
+    digit_value_impl(value)
+}
+
+/// Returns a newly allocated list of the characters between `start` and `end`, inclusive,
+/// in ascending order. If `start` comes after `end`, the list is empty. Surrogate code
+/// points are not characters, and so are skipped.
+///
+/// (char-range start end) -> (listof char?)
+///
+/// * start : char?
+/// * end : char?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (char-range #\a #\e) ;; => '(#\a #\b #\c #\d #\e)
+/// > (char-range #\z #\a) ;; => '()
+/// ```
+#[function(name = "char-range", constant = true)]
+pub fn char_range(start: char, end: char) -> SteelVal {
+    SteelVal::ListV((start..=end).map(SteelVal::CharV).collect())
+}
+
 fn number_to_string_impl(value: &SteelVal, radix: Option<u32>) -> Result<SteelVal> {
     match value {
         SteelVal::IntV(v) => {
@@ -84,13 +241,54 @@ fn number_to_string_impl(value: &SteelVal, radix: Option<u32>) -> Result<SteelVa
                 Ok(SteelVal::StringV(v.to_string().into()))
             }
         }
-        SteelVal::NumV(n) => Ok(SteelVal::StringV(n.to_string().into())),
+        SteelVal::NumV(n) => Ok(SteelVal::StringV(float_to_string(*n).into())),
         SteelVal::BigNum(n) => Ok(SteelVal::StringV(n.to_string().into())),
+        SteelVal::Rational(r) => Ok(SteelVal::StringV(
+            format!("{}/{}", r.numer(), r.denom()).into(),
+        )),
+        SteelVal::BigRational(r) => Ok(SteelVal::StringV(
+            format!("{}/{}", r.numer(), r.denom()).into(),
+        )),
         _ => stop!(TypeMismatch => "number->string expects a number type, found: {}", value),
     }
 }
 
-/// Converts the given number to a string
+// Writes a float so that `string->number` reads back exactly the same value. The debug
+// representation is the shortest one that round trips, and always marks the number as
+// inexact with either a decimal point or an exponent, so that `1.0` doesn't come back as `1`.
+fn float_to_string(value: f64) -> String {
+    if value.is_nan() {
+        "+nan.0".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+inf.0" } else { "-inf.0" }.to_string()
+    } else {
+        format!("{value:?}")
+    }
+}
+
+// The inverse of `float_to_string` for the values that don't have a literal syntax.
+fn special_float(value: &str) -> Option<f64> {
+    match value {
+        "+inf.0" => Some(f64::INFINITY),
+        "-inf.0" => Some(f64::NEG_INFINITY),
+        "+nan.0" | "-nan.0" => Some(f64::NAN),
+        _ => None,
+    }
+}
+
+/// Converts the given number to a string. Reading the string back with `string->number`
+/// produces the same number, including its exactness.
+///
+/// (number->string num [radix]) -> string?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (number->string 255 16) ;; => "ff"
+/// > (number->string 1/3) ;; => "1/3"
+/// > (number->string 1.0) ;; => "1.0"
+/// > (number->string (/ 1.0 0.0)) ;; => "+inf.0"
+/// ```
 #[function(name = "number->string", constant = true)]
 pub fn number_to_string(value: &SteelVal, mut rest: RestArgsIter<'_, isize>) -> Result<SteelVal> {
     let radix = rest.next();
@@ -110,8 +308,61 @@ pub fn number_to_string(value: &SteelVal, mut rest: RestArgsIter<'_, isize>) ->
     number_to_string_impl(value, radix)
 }
 
+/// Splits a leading `#x`, `#o`, `#b` or `#d` radix prefix off of `value`.
+fn split_radix_prefix(value: &str) -> (Option<u32>, &str) {
+    let mut chars = value.chars();
+
+    if chars.next() != Some('#') {
+        return (None, value);
+    }
+
+    let radix = match chars.next() {
+        Some('x' | 'X') => 16,
+        Some('o' | 'O') => 8,
+        Some('b' | 'B') => 2,
+        Some('d' | 'D') => 10,
+        _ => return (None, value),
+    };
+
+    (Some(radix), &value[2..])
+}
+
+/// Parses an integer written in `radix`, with an optional leading sign.
+fn parse_integer_with_radix(digits: &str, radix: u32) -> SteelVal {
+    // Both parsers below are more lenient than `string->number` should be, bignums
+    // for instance skip `_` separators, so the digits are checked up front
+    let unsigned = digits.strip_prefix(['+', '-']).unwrap_or(digits);
+
+    if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_digit(radix)) {
+        return SteelVal::BoolV(false);
+    }
+
+    match isize::from_str_radix(digits, radix) {
+        Ok(parsed) => SteelVal::IntV(parsed),
+        // Too large for a fixnum, but it might still be a valid bignum
+        Err(_) => num::BigInt::parse_bytes(digits.as_bytes(), radix)
+            .and_then(|parsed| parsed.into_steelval().ok())
+            .unwrap_or(SteelVal::BoolV(false)),
+    }
+}
+
 fn string_to_number_impl(value: &str, radix: Option<u32>) -> Result<SteelVal> {
-    let expr = crate::parser::parser::Parser::parse(value)?;
+    // Surrounding whitespace is ignored whatever the radix, not just by the reader
+    let value = value.trim();
+
+    // A prefix in the string takes precedence over the radix argument
+    let (prefix_radix, digits) = split_radix_prefix(value);
+
+    match prefix_radix.or(radix) {
+        Some(radix) if radix != 10 => return Ok(parse_integer_with_radix(digits, radix)),
+        _ => {}
+    }
+
+    if let Some(special) = special_float(digits) {
+        return Ok(SteelVal::NumV(special));
+    }
+
+    let expr = crate::parser::parser::Parser::parse(digits)?;
 
     if expr.len() != 1 {
         // stop!(Generic => "")
@@ -123,22 +374,30 @@ fn string_to_number_impl(value: &str, radix: Option<u32>) -> Result<SteelVal> {
     let svalue = SteelVal::try_from(number)?;
 
     match &svalue {
-        SteelVal::IntV(v) => {
-            if let Some(radix) = radix {
-                match isize::from_str_radix(value, radix) {
-                    Ok(parsed) => Ok(SteelVal::IntV(parsed)),
-                    Err(_) => Ok(SteelVal::BoolV(false)),
-                }
-            } else {
-                Ok(SteelVal::IntV(*v))
-            }
-        }
-        SteelVal::NumV(_) | SteelVal::BigNum(_) => Ok(svalue),
+        SteelVal::IntV(_)
+        | SteelVal::NumV(_)
+        | SteelVal::BigNum(_)
+        | SteelVal::Rational(_)
+        | SteelVal::BigRational(_) => Ok(svalue),
         _ => Ok(SteelVal::BoolV(false)),
     }
 }
 
-/// Converts the given string to a number
+/// Converts the given string to a number, returning `#false` if it isn't one.
+///
+/// (string->number str [radix]) -> (or/c number? #false)
+///
+/// An integer is read in the given radix, which defaults to 10. A `#x`, `#o`, `#b` or `#d`
+/// prefix in the string takes precedence over the radix argument. Surrounding whitespace
+/// is ignored.
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string->number "ff" 16) ;; => 255
+/// > (string->number "#b1010") ;; => 10
+/// > (string->number "12" 2) ;; => #false
+/// ```
 #[function(name = "string->number", constant = true)]
 pub fn string_to_number(
     value: &SteelString,
@@ -163,6 +422,27 @@ pub fn string_to_number(
     }
 }
 
+/// Checks whether the string can be read as a number by `string->number`, without
+/// keeping the number around. Surrounding whitespace is ignored, just as it is by
+/// `string->number`.
+///
+/// (string->number? str [radix]) -> bool?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string->number? "42") ;; => #true
+/// > (string->number? "-1.5e3") ;; => #true
+/// > (string->number? "ff" 16) ;; => #true
+/// > (string->number? "4 2") ;; => #false
+/// > (string->number? "abc") ;; => #false
+/// ```
+#[function(name = "string->number?", constant = true)]
+pub fn is_string_number(value: &SteelString, rest: RestArgsIter<'_, isize>) -> Result<SteelVal> {
+    let parsed = string_to_number(value, rest)?;
+    Ok(SteelVal::BoolV(!matches!(parsed, SteelVal::BoolV(false))))
+}
+
 /// Constructs a string from the given characters
 #[function(name = "string")]
 pub fn string_constructor(rest: RestArgsIter<'_, char>) -> Result<SteelVal> {
@@ -176,7 +456,7 @@ pub fn string_less_than_equal_to(left: &SteelString, right: &SteelString) -> boo
 
 #[function(name = "string-ci<=?", constant = true)]
 pub fn string_ci_less_than_equal_to(left: &SteelString, right: &SteelString) -> bool {
-    left.to_lowercase() <= right.to_lowercase()
+    string_foldcase_impl(left) <= string_foldcase_impl(right)
 }
 
 #[function(name = "string<?", constant = true)]
@@ -186,7 +466,7 @@ pub fn string_less_than(left: &SteelString, right: &SteelString) -> bool {
 
 #[function(name = "string-ci<?", constant = true)]
 pub fn string_ci_less_than(left: &SteelString, right: &SteelString) -> bool {
-    left.to_lowercase() < right.to_lowercase()
+    string_foldcase_impl(left) < string_foldcase_impl(right)
 }
 
 #[function(name = "string>=?", constant = true)]
@@ -196,7 +476,7 @@ pub fn string_greater_than_equal_to(left: &SteelString, right: &SteelString) ->
 
 #[function(name = "string-ci>=?", constant = true)]
 pub fn string_ci_greater_than_equal_to(left: &SteelString, right: &SteelString) -> bool {
-    left.to_lowercase() >= right.to_lowercase()
+    string_foldcase_impl(left) >= string_foldcase_impl(right)
 }
 
 #[function(name = "string>?", constant = true)]
@@ -206,7 +486,7 @@ pub fn string_greater_than(left: &SteelString, right: &SteelString) -> bool {
 
 #[function(name = "string-ci>?", constant = true)]
 pub fn string_ci_greater_than(left: &SteelString, right: &SteelString) -> bool {
-    left.to_lowercase() > right.to_lowercase()
+    string_foldcase_impl(left) > string_foldcase_impl(right)
 }
 
 #[function(name = "string=?", constant = true)]
@@ -216,11 +496,13 @@ pub fn string_equals(left: &SteelString, right: &SteelString) -> bool {
 
 #[function(name = "string-ci=?", constant = true)]
 pub fn string_ci_equals(left: &SteelString, right: &SteelString) -> bool {
-    left.to_lowercase() == right.to_lowercase()
+    string_foldcase_impl(left) == string_foldcase_impl(right)
 }
 
 #[function(name = "string-ref", constant = true)]
-pub fn string_ref(value: &SteelString, index: usize) -> Result<SteelVal> {
+pub fn string_ref(value: &SteelString, index: &SteelVal) -> Result<SteelVal> {
+    let index = index_argument("string-ref", index)?;
+
     if index >= value.len() {
         stop!(Generic => "string-ref: index out of bounds: index: {}, string length: {}", index, value);
     }
@@ -229,7 +511,10 @@ pub fn string_ref(value: &SteelString, index: usize) -> Result<SteelVal> {
 }
 
 #[function(name = "substring", constant = true)]
-pub fn substring(value: &SteelString, i: usize, j: usize) -> Result<SteelVal> {
+pub fn substring(value: &SteelString, i: &SteelVal, j: &SteelVal) -> Result<SteelVal> {
+    let i = index_argument("substring", i)?;
+    let j = index_argument("substring", j)?;
+
     if i >= value.len() {
         stop!(Generic => "substring: index out of bounds: left bound: {}, string length: {}", i, value.len());
     }
@@ -255,11 +540,129 @@ pub fn make_string(k: usize, mut c: RestArgsIter<'_, char>) -> Result<SteelVal>
     Ok((0..k).into_iter().map(|_| c).collect::<String>().into())
 }
 
+fn pad_arguments(name: &str, mut fill: RestArgsIter<'_, char>) -> Result<char> {
+    let c = fill.next();
+
+    if let Some(next) = fill.next() {
+        stop!(ArityMismatch => format!("{} expected 2 or 3 arguments, got an additional argument {}", name, next?))
+    }
+
+    c.unwrap_or(Ok(' '))
+}
+
+/// Pads the string on the left with `char` until it is `width` characters long.
+/// If the string is already longer than `width`, the characters at the start are dropped,
+/// keeping the last `width` characters.
+///
+/// (string-pad-left string? width [char]) -> string?
+///
+/// * width : int? - the length of the resulting string
+/// * char : char? = #\space - the character to pad with
+///
+/// # Examples
+/// ```scheme
+/// > (string-pad-left "42" 5) ;; => "   42"
+/// > (string-pad-left "42" 5 #\0) ;; => "00042"
+/// > (string-pad-left "12345" 3) ;; => "345"
+/// ```
+#[function(name = "string-pad-left")]
+pub fn string_pad_left(
+    value: &SteelString,
+    width: usize,
+    fill: RestArgsIter<'_, char>,
+) -> Result<SteelVal> {
+    let fill = pad_arguments("string-pad-left", fill)?;
+    let length = value.chars().count();
+
+    let padded = if length >= width {
+        value.chars().skip(length - width).collect::<String>()
+    } else {
+        std::iter::repeat(fill)
+            .take(width - length)
+            .chain(value.chars())
+            .collect::<String>()
+    };
+
+    Ok(SteelVal::StringV(padded.into()))
+}
+
+/// Pads the string on the right with `char` until it is `width` characters long.
+/// If the string is already longer than `width`, the characters at the end are dropped,
+/// keeping the first `width` characters.
+///
+/// (string-pad-right string? width [char]) -> string?
+///
+/// * width : int? - the length of the resulting string
+/// * char : char? = #\space - the character to pad with
+///
+/// # Examples
+/// ```scheme
+/// > (string-pad-right "42" 5) ;; => "42   "
+/// > (string-pad-right "42" 5 #\.) ;; => "42..."
+/// > (string-pad-right "12345" 3) ;; => "123"
+/// ```
+#[function(name = "string-pad-right")]
+pub fn string_pad_right(
+    value: &SteelString,
+    width: usize,
+    fill: RestArgsIter<'_, char>,
+) -> Result<SteelVal> {
+    let fill = pad_arguments("string-pad-right", fill)?;
+    let length = value.chars().count();
+
+    let padded = value
+        .chars()
+        .take(width)
+        .chain(std::iter::repeat(fill).take(width.saturating_sub(length)))
+        .collect::<String>();
+
+    Ok(SteelVal::StringV(padded.into()))
+}
+
+/// Returns a new string where occurrences of `from` in `value` are replaced with `to`,
+/// scanning left to right. All occurrences are replaced, unless `count` is given, in which
+/// case only the first `count` are. An empty `from` is an error, since it would match
+/// between every character.
+///
+/// (string-replace value from to [count]) -> string?
+///
+/// * value : string?
+/// * from : string?
+/// * to : string?
+/// * count : int? (non negative)
+///
+/// # Examples
+/// ```scheme
+/// > (string-replace "a-b-c" "-" "+") ;; => "a+b+c"
+/// > (string-replace "a-b-c" "-" "+" 1) ;; => "a+b-c"
+/// > (string-replace "abc" "x" "y") ;; => "abc"
+/// ```
 #[function(name = "string-replace")]
-pub fn replace(value: &SteelString, from: &SteelString, to: &SteelString) -> Result<SteelVal> {
-    Ok(SteelVal::StringV(
-        value.replace(from.as_str(), to.as_str()).into(),
-    ))
+pub fn replace(
+    value: &SteelString,
+    from: &SteelString,
+    to: &SteelString,
+    mut rest: RestArgsIter<'_, isize>,
+) -> Result<SteelVal> {
+    if from.is_empty() {
+        stop!(ContractViolation => "string-replace expects a non empty string to search for");
+    }
+
+    let count = rest.next().transpose()?;
+
+    if let Some(next) = rest.next() {
+        stop!(ArityMismatch => "string-replace expects 3 or 4 arguments, found an additional argument: {}", next?);
+    }
+
+    let replaced = match count {
+        None => value.replace(from.as_str(), to.as_str()),
+        Some(count) if count < 0 => {
+            stop!(ContractViolation => "string-replace expects a non negative count, found: {}", count)
+        }
+        Some(count) => value.replacen(from.as_str(), to.as_str(), count as usize),
+    };
+
+    Ok(SteelVal::StringV(replaced.into()))
 }
 
 /// Concatenatives all of the inputs to their string representation, separated by spaces.
@@ -293,6 +696,10 @@ pub fn to_string(args: &[SteelVal]) -> Result<SteelVal> {
 
 /// Converts a string into a symbol.
 ///
+/// The symbol shares the string's storage rather than going through the global intern
+/// table, so creating many transient symbols doesn't grow the table. Symbols with the
+/// same name are still `eq?`.
+///
 /// (string->symbol string?) -> symbol?
 ///
 /// # Examples
@@ -386,6 +793,21 @@ pub fn string_to_lower(value: &SteelString) -> String {
     value.to_lowercase()
 }
 
+/// Applies full Unicode case folding to the string. Unlike `string->lower`, characters
+/// can fold to multiple characters, so the result may be longer than the input.
+///
+/// (string-foldcase string?) -> string?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string-foldcase "Straße") ;; => "strasse"
+/// ```
+#[function(name = "string-foldcase")]
+pub fn string_foldcase(value: &SteelString) -> String {
+    string_foldcase_impl(value)
+}
+
 /// Returns a new string with the leading and trailing whitespace removed.
 ///
 /// (trim string?) -> string?
@@ -460,6 +882,210 @@ pub fn trim_start_matches(value: &SteelString, pat: &SteelString) -> String {
     value.trim_start_matches(pat.as_str()).into()
 }
 
+fn string_trim_impl(
+    ctx: &mut VmCore,
+    args: &[SteelVal],
+    name: &str,
+    left: bool,
+    right: bool,
+) -> Result<SteelVal> {
+    if args.is_empty() || args.len() > 2 {
+        stop!(ArityMismatch => "{} takes one or two arguments, found: {}", name, args.len())
+    }
+
+    let value = match &args[0] {
+        SteelVal::StringV(s) => s,
+        other => stop!(TypeMismatch => "{} expects a string, found: {}", name, other),
+    };
+
+    let to_trim = args.get(1);
+
+    match to_trim {
+        None | Some(SteelVal::CharV(_)) | Some(SteelVal::StringV(_)) => {}
+        Some(f) if f.is_function() => {}
+        Some(other) => {
+            stop!(TypeMismatch => "{} expects a character, a string of characters or a predicate to trim, found: {}", name, other)
+        }
+    }
+
+    let mut should_trim = |c: char| -> Result<bool> {
+        match to_trim {
+            Some(SteelVal::CharV(t)) => Ok(c == *t),
+            Some(SteelVal::StringV(set)) => Ok(set.contains(c)),
+            Some(pred) => Ok(ctx
+                .call_function_one_arg(pred, SteelVal::CharV(c))?
+                .is_truthy()),
+            None => Ok(c.is_whitespace()),
+        }
+    };
+
+    let mut trimmed = value.as_str();
+
+    if left {
+        let mut start = trimmed.len();
+
+        for (i, c) in trimmed.char_indices() {
+            if !should_trim(c)? {
+                start = i;
+                break;
+            }
+        }
+
+        trimmed = &trimmed[start..];
+    }
+
+    if right {
+        let mut end = 0;
+
+        for (i, c) in trimmed.char_indices().rev() {
+            if !should_trim(c)? {
+                end = i + c.len_utf8();
+                break;
+            }
+        }
+
+        trimmed = &trimmed[..end];
+    }
+
+    Ok(SteelVal::StringV(trimmed.into()))
+}
+
+/// Returns a new string with the characters to trim removed from both ends. By default
+/// Unicode whitespace is trimmed, otherwise `to-trim` picks the characters to remove:
+/// a single character, a string holding the set of characters, or a predicate on characters.
+///
+/// (string-trim string? [to-trim]) -> string?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string-trim "  foo  ") ;; => "foo"
+/// > (string-trim "xxfooxx" #\x) ;; => "foo"
+/// > (string-trim "-=foo=-" "=-") ;; => "foo"
+/// > (string-trim "123foo45" char-digit?) ;; => "foo"
+/// ```
+pub fn string_trim() -> SteelVal {
+    SteelVal::BuiltIn(
+        |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+            Some(string_trim_impl(ctx, args, "string-trim", true, true))
+        },
+    )
+}
+
+/// Like `string-trim`, but only trims the start of the string.
+///
+/// (string-trim-left string? [to-trim]) -> string?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string-trim-left "  foo  ") ;; => "foo  "
+/// ```
+pub fn string_trim_left() -> SteelVal {
+    SteelVal::BuiltIn(
+        |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+            Some(string_trim_impl(ctx, args, "string-trim-left", true, false))
+        },
+    )
+}
+
+/// Like `string-trim`, but only trims the end of the string.
+///
+/// (string-trim-right string? [to-trim]) -> string?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string-trim-right "  foo  ") ;; => "  foo"
+/// ```
+pub fn string_trim_right() -> SteelVal {
+    SteelVal::BuiltIn(
+        |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+            Some(string_trim_impl(
+                ctx,
+                args,
+                "string-trim-right",
+                false,
+                true,
+            ))
+        },
+    )
+}
+
+fn string_split_impl(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal> {
+    if args.is_empty() || args.len() > 2 {
+        stop!(ArityMismatch => "string-split takes one or two arguments, found: {}", args.len())
+    }
+
+    let value = match &args[0] {
+        SteelVal::StringV(s) => s.as_str(),
+        other => stop!(TypeMismatch => "string-split expects a string, found: {}", other),
+    };
+
+    let mut pieces = Vec::new();
+
+    match args.get(1) {
+        None => pieces.extend(value.split(char::is_whitespace)),
+        Some(SteelVal::CharV(c)) => pieces.extend(value.split(*c)),
+        Some(SteelVal::StringV(sep)) if sep.is_empty() => {
+            stop!(Generic => "string-split: the separator cannot be the empty string")
+        }
+        Some(SteelVal::StringV(sep)) => pieces.extend(value.split(sep.as_str())),
+        Some(pred) if pred.is_function() => {
+            let mut start = 0;
+
+            for (i, c) in value.char_indices() {
+                if ctx
+                    .call_function_one_arg(pred, SteelVal::CharV(c))?
+                    .is_truthy()
+                {
+                    pieces.push(&value[start..i]);
+                    start = i + c.len_utf8();
+                }
+            }
+
+            pieces.push(&value[start..]);
+        }
+        Some(other) => {
+            stop!(TypeMismatch => "string-split expects a character, a string or a predicate to split on, found: {}", other)
+        }
+    }
+
+    // Runs of separators are collapsed, which is the same as dropping the empty pieces
+    Ok(pieces
+        .into_iter()
+        .filter(|x| !x.is_empty())
+        .map(|x| SteelVal::StringV(x.into()))
+        .collect::<List<_>>()
+        .into())
+}
+
+/// Splits the string on the given separator, which is either a character, a string, or a
+/// predicate on characters, splitting at every character that satisfies it. Without a
+/// separator the string is split on whitespace.
+///
+/// Consecutive separators are treated as a single one, and separators at the start or
+/// end of the string are ignored, so the result never contains empty strings. Use
+/// `split-many` to keep the empty fields.
+///
+/// (string-split string? [separator]) -> (listof string?)
+///
+/// # Examples
+///
+/// ```scheme
+/// > (string-split "  foo bar ") ;; => '("foo" "bar")
+/// > (string-split "a,b,,c" #\,) ;; => '("a" "b" "c")
+/// > (string-split "a::b" "::") ;; => '("a" "b")
+/// > (string-split "a1b2c" char-numeric?) ;; => '("a" "b" "c")
+/// ```
+pub fn string_split() -> SteelVal {
+    SteelVal::BuiltIn(
+        |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+            Some(string_split_impl(ctx, args))
+        },
+    )
+}
+
 /// Returns a list of strings from the original string split on the whitespace
 ///
 /// (split-whitespace string?) -> (listof string?)
@@ -565,6 +1191,104 @@ pub fn string_append(mut rest: RestArgsIter<'_, &SteelString>) -> Result<SteelVa
         .map(|x| SteelVal::StringV(x.into()))
 }
 
+/// Formats the given arguments according to the directives in the format string.
+/// If the first argument is a port, the result is written to that port instead of
+/// being returned.
+///
+/// (format fmt args ...) -> string?
+/// (format port fmt args ...) -> void?
+///
+/// Supported directives:
+/// * `~a` : displays the next argument
+/// * `~s` : writes the next argument, so strings and characters are printed as literals
+/// * `~%` : a newline
+/// * `~~` : a literal tilde
+///
+/// # Examples
+/// ```scheme
+/// > (format "~a + ~a = ~a" 1 2 3) ;; => "1 + 2 = 3"
+/// > (format "~a and ~s" "foo" "bar") ;; => "foo and \"bar\""
+/// ```
+#[native(name = "format", arity = "AtLeast(1)")]
+pub fn format(args: &[SteelVal]) -> Result<SteelVal> {
+    match args {
+        [SteelVal::PortV(port), SteelVal::StringV(fmt), rest @ ..] => {
+            let output = format_impl(fmt, rest)?;
+            port.write_string(&output).map(|_| SteelVal::Void)
+        }
+        [SteelVal::StringV(fmt), rest @ ..] => {
+            format_impl(fmt, rest).map(|output| SteelVal::StringV(output.into()))
+        }
+        [SteelVal::PortV(_), other, ..] | [other, ..] => {
+            stop!(TypeMismatch => "format expects a format string, found: {}", other)
+        }
+        [] => stop!(ArityMismatch => "format expects at least one argument, found none"),
+    }
+}
+
+fn format_impl(fmt: &str, args: &[SteelVal]) -> Result<String> {
+    let mut output = String::with_capacity(fmt.len());
+    let mut args = args.iter();
+    let mut chars = fmt.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('a') | Some('A') => match args.next() {
+                Some(arg) => output.push_str(&format_display(arg)),
+                None => {
+                    stop!(ArityMismatch => "format: not enough arguments for the directives in {:?}", fmt)
+                }
+            },
+            Some('s') | Some('S') => match args.next() {
+                Some(arg) => output.push_str(&format_write(arg)),
+                None => {
+                    stop!(ArityMismatch => "format: not enough arguments for the directives in {:?}", fmt)
+                }
+            },
+            Some('%') => output.push('\n'),
+            Some('~') => output.push('~'),
+            Some(other) => stop!(Generic => "format: unknown directive ~{}", other),
+            None => stop!(Generic => "format: format string ends with an incomplete directive"),
+        }
+    }
+
+    if args.next().is_some() {
+        stop!(ArityMismatch => "format: too many arguments for the directives in {:?}", fmt);
+    }
+
+    Ok(output)
+}
+
+// The top level printer quotes symbols, lists and vectors - neither
+// display nor write want that here.
+fn format_write(value: &SteelVal) -> String {
+    match value {
+        SteelVal::SymbolV(_) | SteelVal::ListV(_) | SteelVal::VectorV(_) => {
+            let output = value.to_string();
+            output.strip_prefix('\'').unwrap_or(&output).to_string()
+        }
+        // Mutable vectors don't go through the quoting printer at all
+        SteelVal::MutableVector(v) => {
+            let items = v.get().iter().map(format_write).collect::<Vec<_>>();
+            format!("#({})", items.join(" "))
+        }
+        _ => value.to_string(),
+    }
+}
+
+fn format_display(value: &SteelVal) -> String {
+    match value {
+        SteelVal::StringV(s) => s.to_string(),
+        SteelVal::CharV(c) => c.to_string(),
+        _ => format_write(value),
+    }
+}
+
 #[cfg(test)]
 mod string_operation_tests {
     use super::*;
@@ -646,6 +1370,86 @@ mod string_operation_tests {
         ("split-whitespace", split_whitespace_arity_takes_string, steel_split_whitespace)
     }
 
+    #[test]
+    fn format_directives() {
+        let args = vec![
+            SteelVal::StringV("~a ~s ~a~%~~".into()),
+            SteelVal::StringV("foo".into()),
+            SteelVal::StringV("bar".into()),
+            SteelVal::IntV(10),
+        ];
+        let res = format(&args);
+        let expected = SteelVal::StringV("foo \"bar\" 10\n~".into());
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn format_display_and_write_chars() {
+        let args = vec![
+            SteelVal::StringV("~a~s".into()),
+            SteelVal::CharV('x'),
+            SteelVal::CharV('y'),
+        ];
+        let res = format(&args);
+        let expected = SteelVal::StringV("x#\\y".into());
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn format_vectors_without_quote() {
+        let args = vec![
+            SteelVal::StringV("~a ~s".into()),
+            im_rc::vector![SteelVal::IntV(1), SteelVal::IntV(2)].into(),
+            im_rc::vector![SteelVal::StringV("x".into())].into(),
+        ];
+        let res = format(&args);
+        let expected = SteelVal::StringV("#(1 2) #(\"x\")".into());
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn format_missing_arguments() {
+        let args = vec![SteelVal::StringV("~a ~a".into()), SteelVal::IntV(1)];
+        let res = format(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ArityMismatch);
+    }
+
+    #[test]
+    fn format_extra_arguments() {
+        let args = vec![
+            SteelVal::StringV("~a".into()),
+            SteelVal::IntV(1),
+            SteelVal::IntV(2),
+        ];
+        let res = format(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ArityMismatch);
+    }
+
+    #[test]
+    fn format_unknown_directive() {
+        let args = vec![SteelVal::StringV("~q".into()), SteelVal::IntV(1)];
+        let res = format(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Generic);
+    }
+
+    #[test]
+    fn char_foldcase_differs_from_downcase() {
+        // Final sigma lowercases to itself, but folds to the regular sigma
+        assert_eq!('\u{03C2}'.to_lowercase().next(), Some('\u{03C2}'));
+        assert_eq!(char_foldcase_impl('\u{03C2}'), '\u{03C3}');
+        assert_eq!(char_foldcase_impl('\u{03A3}'), '\u{03C3}');
+        assert_eq!(char_foldcase_impl('\u{00B5}'), '\u{03BC}');
+        assert_eq!(char_foldcase_impl('\u{017F}'), 's');
+        assert_eq!(char_foldcase_impl('A'), 'a');
+    }
+
+    #[test]
+    fn string_foldcase_expands_sharp_s() {
+        assert_eq!(string_foldcase_impl("Stra\u{00DF}e"), "strasse");
+        assert_eq!(string_foldcase_impl("STRASSE"), "strasse");
+        assert_eq!(string_foldcase_impl("\u{1E9E}"), "ss");
+    }
+
     #[test]
     fn string_append_test_normal() {
         let args = vec![
@@ -797,4 +1601,342 @@ mod string_operation_tests {
         );
         assert_eq!(res.unwrap(), expected);
     }
+
+    #[test]
+    fn string_pad_left_pads_shorter_strings() {
+        let args = vec![SteelVal::StringV("42".into()), SteelVal::IntV(5)];
+        let res = steel_string_pad_left(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("   42".into()));
+    }
+
+    #[test]
+    fn string_pad_right_pads_shorter_strings() {
+        let args = vec![SteelVal::StringV("42".into()), SteelVal::IntV(5)];
+        let res = steel_string_pad_right(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("42   ".into()));
+    }
+
+    #[test]
+    fn string_pad_left_truncation_keeps_the_end() {
+        let args = vec![SteelVal::StringV("12345".into()), SteelVal::IntV(3)];
+        let res = steel_string_pad_left(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("345".into()));
+    }
+
+    #[test]
+    fn string_pad_right_truncation_keeps_the_start() {
+        let args = vec![SteelVal::StringV("12345".into()), SteelVal::IntV(3)];
+        let res = steel_string_pad_right(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("123".into()));
+    }
+
+    #[test]
+    fn string_pad_with_custom_fill() {
+        let args = vec![
+            SteelVal::StringV("7".into()),
+            SteelVal::IntV(3),
+            SteelVal::CharV('0'),
+        ];
+        assert_eq!(
+            steel_string_pad_left(&args).unwrap(),
+            SteelVal::StringV("007".into())
+        );
+        assert_eq!(
+            steel_string_pad_right(&args).unwrap(),
+            SteelVal::StringV("700".into())
+        );
+    }
+
+    #[test]
+    fn string_pad_counts_characters_not_bytes() {
+        let args = vec![
+            SteelVal::StringV("\u{00E9}t\u{00E9}".into()),
+            SteelVal::IntV(4),
+        ];
+        assert_eq!(
+            steel_string_pad_left(&args).unwrap(),
+            SteelVal::StringV(" \u{00E9}t\u{00E9}".into())
+        );
+    }
+
+    #[test]
+    fn string_pad_too_many_arguments() {
+        let args = vec![
+            SteelVal::StringV("7".into()),
+            SteelVal::IntV(3),
+            SteelVal::CharV('0'),
+            SteelVal::CharV('1'),
+        ];
+        let res = steel_string_pad_left(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ArityMismatch);
+    }
+
+    #[test]
+    fn digit_value_ascii_digits() {
+        for (i, c) in ('0'..='9').enumerate() {
+            assert_eq!(digit_value_impl(c), Some(i as u32));
+        }
+    }
+
+    #[test]
+    fn digit_value_non_digits() {
+        assert_eq!(digit_value_impl('a'), None);
+        assert_eq!(digit_value_impl(' '), None);
+        assert_eq!(digit_value_impl('/'), None);
+        assert_eq!(digit_value_impl(':'), None);
+        // Roman numerals are numeric, but aren't decimal digits
+        assert_eq!(digit_value_impl('\u{2163}'), None);
+    }
+
+    #[test]
+    fn digit_value_unicode_digits() {
+        assert_eq!(digit_value_impl('\u{0664}'), Some(4));
+        assert_eq!(digit_value_impl('\u{0AE6}'), Some(0));
+        assert_eq!(digit_value_impl('\u{FF19}'), Some(9));
+        assert_eq!(digit_value_impl('\u{1D7D9}'), Some(1));
+    }
+
+    #[test]
+    fn string_to_number_with_radix() {
+        let parse = |value: &str, radix: Option<u32>| string_to_number_impl(value, radix).unwrap();
+
+        assert_eq!(parse("ff", Some(16)), SteelVal::IntV(255));
+        assert_eq!(parse("FF", Some(16)), SteelVal::IntV(255));
+        assert_eq!(parse("1010", Some(2)), SteelVal::IntV(10));
+        assert_eq!(parse("-777", Some(8)), SteelVal::IntV(-511));
+        assert_eq!(parse("42", Some(10)), SteelVal::IntV(42));
+        assert_eq!(parse("42", None), SteelVal::IntV(42));
+    }
+
+    #[test]
+    fn string_to_number_with_radix_prefix() {
+        let parse = |value: &str, radix: Option<u32>| string_to_number_impl(value, radix).unwrap();
+
+        assert_eq!(parse("#xff", None), SteelVal::IntV(255));
+        assert_eq!(parse("#b101", None), SteelVal::IntV(5));
+        assert_eq!(parse("#o17", None), SteelVal::IntV(15));
+        assert_eq!(parse("#d99", None), SteelVal::IntV(99));
+
+        // The prefix wins over the radix argument
+        assert_eq!(parse("#xff", Some(2)), SteelVal::IntV(255));
+        assert_eq!(parse("#d10", Some(16)), SteelVal::IntV(10));
+    }
+
+    #[test]
+    fn string_to_number_invalid_for_radix() {
+        let parse = |value: &str, radix: Option<u32>| string_to_number_impl(value, radix).unwrap();
+
+        assert_eq!(parse("102", Some(2)), SteelVal::BoolV(false));
+        assert_eq!(parse("fg", Some(16)), SteelVal::BoolV(false));
+        assert_eq!(parse("", Some(16)), SteelVal::BoolV(false));
+        assert_eq!(parse("#b", None), SteelVal::BoolV(false));
+        assert_eq!(parse("#b2", None), SteelVal::BoolV(false));
+        assert_eq!(parse("1.5", Some(16)), SteelVal::BoolV(false));
+        assert_eq!(parse("1_0", Some(16)), SteelVal::BoolV(false));
+        assert_eq!(parse("_1", Some(16)), SteelVal::BoolV(false));
+        assert_eq!(
+            parse("ffffffffffffffffffff_f", Some(16)),
+            SteelVal::BoolV(false)
+        );
+    }
+
+    #[test]
+    fn string_to_number_large_with_radix() {
+        let parsed = string_to_number_impl("ffffffffffffffffffff", Some(16)).unwrap();
+        assert_eq!(
+            parsed.to_string(),
+            num::BigInt::parse_bytes(b"ffffffffffffffffffff", 16)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn string_replace_all_occurrences() {
+        let args = vec![
+            SteelVal::StringV("a-b-c".into()),
+            SteelVal::StringV("-".into()),
+            SteelVal::StringV("+".into()),
+        ];
+        let res = steel_replace(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("a+b+c".into()));
+    }
+
+    #[test]
+    fn string_replace_limited_count() {
+        let args = vec![
+            SteelVal::StringV("a-b-c".into()),
+            SteelVal::StringV("-".into()),
+            SteelVal::StringV("+".into()),
+            SteelVal::IntV(1),
+        ];
+        let res = steel_replace(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("a+b-c".into()));
+    }
+
+    #[test]
+    fn string_replace_no_match() {
+        let args = vec![
+            SteelVal::StringV("abc".into()),
+            SteelVal::StringV("x".into()),
+            SteelVal::StringV("y".into()),
+        ];
+        let res = steel_replace(&args);
+        assert_eq!(res.unwrap(), SteelVal::StringV("abc".into()));
+    }
+
+    #[test]
+    fn string_replace_rejects_empty_search() {
+        let args = vec![
+            SteelVal::StringV("abc".into()),
+            SteelVal::StringV("".into()),
+            SteelVal::StringV("y".into()),
+        ];
+        let res = steel_replace(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ContractViolation);
+    }
+
+    #[test]
+    fn string_replace_rejects_negative_count() {
+        let args = vec![
+            SteelVal::StringV("abc".into()),
+            SteelVal::StringV("b".into()),
+            SteelVal::StringV("y".into()),
+            SteelVal::IntV(-1),
+        ];
+        let res = steel_replace(&args);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ContractViolation);
+    }
+
+    #[test]
+    fn char_range_is_inclusive() {
+        let args = vec![SteelVal::CharV('a'), SteelVal::CharV('c')];
+        let res = steel_char_range(&args);
+        let expected = SteelVal::ListV(
+            vec![
+                SteelVal::CharV('a'),
+                SteelVal::CharV('b'),
+                SteelVal::CharV('c'),
+            ]
+            .into(),
+        );
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn char_range_backwards_is_empty() {
+        let args = vec![SteelVal::CharV('z'), SteelVal::CharV('a')];
+        let res = steel_char_range(&args);
+        assert_eq!(res.unwrap(), SteelVal::ListV(vec![].into()));
+    }
+
+    fn round_trip(value: SteelVal) -> SteelVal {
+        let printed = number_to_string_impl(&value, None).unwrap();
+        let SteelVal::StringV(printed) = printed else {
+            panic!("number->string should return a string, found: {printed}");
+        };
+        string_to_number_impl(printed.as_str(), None).unwrap()
+    }
+
+    #[test]
+    fn number_to_string_round_trips_exact_numbers() {
+        for i in [0, 1, -1, 42, isize::MAX, isize::MIN] {
+            assert_eq!(round_trip(SteelVal::IntV(i)), SteelVal::IntV(i));
+        }
+
+        let big = num::BigInt::parse_bytes(b"123456789012345678901234567890", 10).unwrap();
+        let big = big.into_steelval().unwrap();
+        assert_eq!(round_trip(big.clone()), big);
+
+        for (n, d) in [(1, 2), (-1, 3), (22, 7), (i32::MAX, 3)] {
+            let rational = num::Rational32::new(n, d).into_steelval().unwrap();
+            assert_eq!(round_trip(rational.clone()), rational);
+        }
+    }
+
+    #[test]
+    fn number_to_string_round_trips_floats() {
+        let floats = [
+            0.0,
+            -0.0,
+            1.0,
+            -2.5,
+            0.1,
+            1.0 / 3.0,
+            std::f64::consts::PI,
+            123456789.123456789,
+            1e-7,
+            -4.2e-300,
+            1e16,
+            6.02214076e23,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        for value in floats {
+            match round_trip(SteelVal::NumV(value)) {
+                SteelVal::NumV(parsed) => {
+                    assert_eq!(
+                        parsed.to_bits(),
+                        value.to_bits(),
+                        "{value:?} did not round trip"
+                    )
+                }
+                other => panic!("{value:?} came back as {other}"),
+            }
+        }
+
+        match round_trip(SteelVal::NumV(f64::NAN)) {
+            SteelVal::NumV(parsed) => assert!(parsed.is_nan()),
+            other => panic!("NaN came back as {other}"),
+        }
+    }
+
+    #[test]
+    fn string_number_predicate() {
+        let check = |value: &str| {
+            let args = vec![SteelVal::StringV(value.into())];
+            steel_is_string_number(&args).unwrap()
+        };
+
+        assert_eq!(check("42"), SteelVal::BoolV(true));
+        assert_eq!(check("-1/2"), SteelVal::BoolV(true));
+        assert_eq!(check("1e-7"), SteelVal::BoolV(true));
+        assert_eq!(check("  42\n"), SteelVal::BoolV(true));
+        assert_eq!(check(""), SteelVal::BoolV(false));
+        assert_eq!(check("abc"), SteelVal::BoolV(false));
+        assert_eq!(check("4 2"), SteelVal::BoolV(false));
+        assert_eq!(check("\"42\""), SteelVal::BoolV(false));
+    }
+
+    #[test]
+    fn string_number_predicate_with_radix() {
+        let args = vec![SteelVal::StringV("ff".into()), SteelVal::IntV(16)];
+        assert_eq!(
+            steel_is_string_number(&args).unwrap(),
+            SteelVal::BoolV(true)
+        );
+
+        let args = vec![SteelVal::StringV("12".into()), SteelVal::IntV(2)];
+        assert_eq!(
+            steel_is_string_number(&args).unwrap(),
+            SteelVal::BoolV(false)
+        );
+
+        // Whitespace is ignored with a radix too, just like without one
+        let args = vec![SteelVal::StringV(" ff\n".into()), SteelVal::IntV(16)];
+        assert_eq!(
+            steel_is_string_number(&args).unwrap(),
+            SteelVal::BoolV(true)
+        );
+
+        let args = vec![SteelVal::StringV(" #xff ".into())];
+        assert_eq!(
+            steel_is_string_number(&args).unwrap(),
+            SteelVal::BoolV(true)
+        );
+    }
 }
@@ -1,6 +1,24 @@
+use std::cell::Cell;
+
 use crate::rvals::{Result, SteelVal};
 use crate::stop;
 
+// Shared by every engine on the current thread, including the kernel that runs
+// macro expansion, so generated names are unique per thread rather than per engine.
+thread_local! {
+    static GENSYM_COUNTER: Cell<usize> = Cell::new(0);
+}
+
+/// Restarts the numbering used by `gensym`, so the next symbol generated is the
+/// same one a fresh program would generate first.
+///
+/// The counter is shared by every engine on the current thread, so this resets it for
+/// all of them. Symbols generated before the reset will be generated again afterwards,
+/// so resetting in the middle of a program risks collisions between them.
+pub fn reset_gensym_counter() {
+    GENSYM_COUNTER.with(|counter| counter.set(0));
+}
+
 pub struct SymbolOperations {}
 impl SymbolOperations {
     pub fn concat_symbols() -> SteelVal {
@@ -21,6 +39,48 @@ impl SymbolOperations {
         })
     }
 
+    /// (gensym [prefix]) -> symbol?
+    ///
+    /// Generates a new symbol by appending a counter to `prefix`, which is `gensym` by default.
+    /// The name starts with `##`, so it can't clash with an identifier written in the source.
+    pub fn gensym() -> SteelVal {
+        SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+            let prefix = match args {
+                [] => "gensym",
+                [SteelVal::SymbolV(prefix)] | [SteelVal::StringV(prefix)] => prefix.as_str(),
+                [other] => {
+                    stop!(TypeMismatch => "gensym expects a symbol or a string as the prefix, found: {}", other)
+                }
+                _ => {
+                    stop!(ArityMismatch => "gensym expects at most one argument, found: {}", args.len())
+                }
+            };
+
+            let count = GENSYM_COUNTER.with(|counter| {
+                counter.set(counter.get() + 1);
+                counter.get()
+            });
+
+            Ok(SteelVal::SymbolV(format!("##{prefix}{count}").into()))
+        })
+    }
+
+    /// (gensym-reset!) -> void?
+    ///
+    /// Restarts the numbering used by `gensym`. Any symbols generated before the reset
+    /// will be generated again, so this is meant for getting reproducible names in tests.
+    pub fn gensym_reset() -> SteelVal {
+        SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+            if !args.is_empty() {
+                stop!(ArityMismatch => "gensym-reset! expects no arguments, found: {}", args.len())
+            }
+
+            reset_gensym_counter();
+
+            Ok(SteelVal::Void)
+        })
+    }
+
     pub fn symbol_to_string() -> SteelVal {
         SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
             if args.len() == 1 {
@@ -66,6 +126,20 @@ mod symbol_tests {
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[test]
+    fn concat_symbols_no_arguments() {
+        let result = apply_function(SymbolOperations::concat_symbols(), vec![]);
+        let expected = SymbolV("".into());
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[test]
+    fn concat_symbols_rejects_non_symbols() {
+        let args = vec![SymbolV("foo".into()), StringV("bar".into())];
+        let result = apply_function(SymbolOperations::concat_symbols(), args);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn symbol_to_string_normal() {
         let args = vec![SymbolV("foo".into())];
@@ -73,4 +147,38 @@ mod symbol_tests {
         let expected = StringV("foo".into());
         assert_eq!(result.unwrap(), expected);
     }
+
+    #[test]
+    fn gensym_reset_repeats_the_sequence() {
+        let generate = |args: Vec<SteelVal>| apply_function(SymbolOperations::gensym(), args);
+
+        reset_gensym_counter();
+        let first = vec![
+            generate(vec![]).unwrap(),
+            generate(vec![SymbolV("tmp".into())]).unwrap(),
+            generate(vec![StringV("x".into())]).unwrap(),
+        ];
+        assert_eq!(
+            first,
+            vec![
+                SymbolV("##gensym1".into()),
+                SymbolV("##tmp2".into()),
+                SymbolV("##x3".into())
+            ]
+        );
+
+        apply_function(SymbolOperations::gensym_reset(), vec![]).unwrap();
+        let second = vec![
+            generate(vec![]).unwrap(),
+            generate(vec![SymbolV("tmp".into())]).unwrap(),
+            generate(vec![StringV("x".into())]).unwrap(),
+        ];
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn gensym_rejects_bad_prefixes() {
+        let result = apply_function(SymbolOperations::gensym(), vec![IntV(1)]);
+        assert!(result.is_err());
+    }
 }
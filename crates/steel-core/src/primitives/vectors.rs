@@ -1,4 +1,5 @@
 use crate::gc::Gc;
+use crate::primitives::nums::index_argument;
 use crate::rvals::{Result, SteelVal};
 use crate::rvals::{SteelVal::*, SteelVector};
 use crate::steel_vm::builtin::BuiltInModule;
@@ -281,6 +282,13 @@ impl VectorOperations {
         )
     }
 
+    /// Creates a mutable vector of length `k`, with every slot holding `fill`, which
+    /// defaults to `0`.
+    ///
+    /// (make-vector k [fill]) -> mutable-vector?
+    ///
+    /// Every slot holds the same `fill` object rather than a copy of it, so when `fill`
+    /// is itself mutable, a change made through one slot is visible through all of them.
     pub fn make_vector() -> SteelVal {
         fn make_vector_impl(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal> {
             match &args {
@@ -290,6 +298,9 @@ impl VectorOperations {
                 &[SteelVal::IntV(i), initial_value] if *i >= 0 => {
                     Ok(ctx.make_mutable_vector(vec![initial_value.clone(); *i as usize]))
                 }
+                &[SteelVal::IntV(i)] | &[SteelVal::IntV(i), _] => {
+                    stop!(Generic => "make-vector expects a non-negative length, found: {}", i)
+                }
                 _ => {
                     stop!(TypeMismatch => "make-vector expects a positive integer, and optionally a value to initialize the vector with, found: {:?}", args)
                 }
@@ -303,6 +314,284 @@ impl VectorOperations {
         )
     }
 
+    pub fn vec_copy() -> SteelVal {
+        fn vec_copy_impl(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal> {
+            if args.is_empty() || args.len() > 3 {
+                stop!(ArityMismatch => "vector-copy takes between one and three arguments, found: {}", args.len())
+            }
+
+            match &args[0] {
+                SteelVal::VectorV(v) => {
+                    let (start, end) = vector_range_arguments("vector-copy", &args[1..], v.len())?;
+
+                    Ok(SteelVal::VectorV(
+                        Gc::new(
+                            v.iter()
+                                .skip(start)
+                                .take(end - start)
+                                .cloned()
+                                .collect::<Vector<_>>(),
+                        )
+                        .into(),
+                    ))
+                }
+                SteelVal::MutableVector(v) => {
+                    let copied = {
+                        let ptr = v.strong_ptr();
+                        let guard = &ptr.borrow().value;
+                        let (start, end) =
+                            vector_range_arguments("vector-copy", &args[1..], guard.len())?;
+                        guard[start..end].to_vec()
+                    };
+
+                    Ok(ctx.make_mutable_vector(copied))
+                }
+                other => {
+                    stop!(TypeMismatch => "vector-copy expects a vector, found: {}", other)
+                }
+            }
+        }
+
+        SteelVal::BuiltIn(
+            |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+                Some(vec_copy_impl(ctx, args))
+            },
+        )
+    }
+
+    // (vector-copy! to at from [start [end]])
+    pub fn mut_vec_copy() -> SteelVal {
+        SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+            if args.len() < 3 || args.len() > 5 {
+                stop!(ArityMismatch => "vector-copy! takes between three and five arguments, found: {}", args.len())
+            }
+
+            let destination = match &args[0] {
+                SteelVal::MutableVector(v) => v,
+                other => {
+                    stop!(TypeMismatch => "vector-copy! expects a mutable vector as the destination, found: {}", other)
+                }
+            };
+
+            let at = match &args[1] {
+                SteelVal::IntV(i) if *i >= 0 => *i as usize,
+                other => {
+                    stop!(TypeMismatch => "vector-copy! expects a non negative integer offset, found: {}", other)
+                }
+            };
+
+            let values = match &args[2] {
+                // The source and destination are the same vector, so the ranges might
+                // overlap. Copy in the direction that reads each element before it gets overwritten.
+                SteelVal::MutableVector(source) if source.ptr_eq(destination) => {
+                    let ptr = destination.strong_ptr();
+                    let guard = &mut ptr.borrow_mut().value;
+                    let (start, end) =
+                        vector_range_arguments("vector-copy!", &args[3..], guard.len())?;
+                    check_copy_destination(at, end - start, guard.len())?;
+
+                    if at <= start {
+                        for i in 0..(end - start) {
+                            guard[at + i] = guard[start + i].clone();
+                        }
+                    } else {
+                        for i in (0..(end - start)).rev() {
+                            guard[at + i] = guard[start + i].clone();
+                        }
+                    }
+
+                    return Ok(SteelVal::Void);
+                }
+                SteelVal::MutableVector(source) => {
+                    let ptr = source.strong_ptr();
+                    let guard = &ptr.borrow().value;
+                    let (start, end) =
+                        vector_range_arguments("vector-copy!", &args[3..], guard.len())?;
+                    guard[start..end].to_vec()
+                }
+                SteelVal::VectorV(source) => {
+                    let (start, end) =
+                        vector_range_arguments("vector-copy!", &args[3..], source.len())?;
+                    source
+                        .iter()
+                        .skip(start)
+                        .take(end - start)
+                        .cloned()
+                        .collect()
+                }
+                other => {
+                    stop!(TypeMismatch => "vector-copy! expects a vector as the source, found: {}", other)
+                }
+            };
+
+            let ptr = destination.strong_ptr();
+            let guard = &mut ptr.borrow_mut().value;
+            check_copy_destination(at, values.len(), guard.len())?;
+
+            for (offset, value) in values.into_iter().enumerate() {
+                guard[at + offset] = value;
+            }
+
+            Ok(SteelVal::Void)
+        })
+    }
+
+    pub fn vector_append() -> SteelVal {
+        fn vector_append_impl(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal> {
+            let mut any_mutable = false;
+            let mut values = Vec::new();
+
+            for arg in args {
+                match arg {
+                    SteelVal::VectorV(v) => values.extend(v.iter().cloned()),
+                    SteelVal::MutableVector(v) => {
+                        any_mutable = true;
+                        values.extend(v.strong_ptr().borrow().value.iter().cloned());
+                    }
+                    other => {
+                        stop!(TypeMismatch => "vector-append expects vectors, found: {}", other)
+                    }
+                }
+            }
+
+            // Only produce an immutable vector if every input was immutable
+            if any_mutable {
+                Ok(ctx.make_mutable_vector(values))
+            } else {
+                Ok(SteelVal::VectorV(
+                    Gc::new(values.into_iter().collect::<Vector<_>>()).into(),
+                ))
+            }
+        }
+
+        SteelVal::BuiltIn(
+            |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+                Some(vector_append_impl(ctx, args))
+            },
+        )
+    }
+
+    /// Returns a new vector of length `k`, holding the elements of `vec` followed by
+    /// `fill` in any new slots. `fill` defaults to `0`, as with `make-vector`. If `k` is
+    /// shorter than `vec`, the elements past `k` are dropped. The result is mutable
+    /// exactly when `vec` is, and `vec` itself is left untouched.
+    ///
+    /// (vector-resize vec k [fill]) -> vector?
+    pub fn vector_resize() -> SteelVal {
+        fn vector_resize_impl(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal> {
+            if args.len() < 2 || args.len() > 3 {
+                stop!(ArityMismatch => "vector-resize takes two or three arguments, found: {}", args.len())
+            }
+
+            let length = match &args[1] {
+                SteelVal::IntV(i) if *i >= 0 => *i as usize,
+                other => {
+                    stop!(TypeMismatch => "vector-resize expects a non negative length, found: {}", other)
+                }
+            };
+
+            let fill = args.get(2).cloned().unwrap_or(SteelVal::IntV(0));
+
+            match &args[0] {
+                SteelVal::VectorV(v) => {
+                    let mut resized = v.take(length.min(v.len()));
+
+                    while resized.len() < length {
+                        resized.push_back(fill.clone());
+                    }
+
+                    Ok(SteelVal::VectorV(Gc::new(resized).into()))
+                }
+                SteelVal::MutableVector(v) => {
+                    let mut resized = v.strong_ptr().borrow().value.clone();
+                    resized.resize(length, fill);
+
+                    Ok(ctx.make_mutable_vector(resized))
+                }
+                other => {
+                    stop!(TypeMismatch => "vector-resize expects a vector, found: {}", other)
+                }
+            }
+        }
+
+        SteelVal::BuiltIn(
+            |ctx: &mut VmCore, args: &[SteelVal]| -> Option<Result<SteelVal>> {
+                Some(vector_resize_impl(ctx, args))
+            },
+        )
+    }
+
+    // (string->vector string [start [end]])
+    pub fn string_to_vector() -> SteelVal {
+        SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+            if args.is_empty() || args.len() > 3 {
+                stop!(ArityMismatch => "string->vector takes between one and three arguments, found: {}", args.len())
+            }
+
+            let string = match &args[0] {
+                SteelVal::StringV(s) => s,
+                other => {
+                    stop!(TypeMismatch => "string->vector expects a string, found: {}", other)
+                }
+            };
+
+            let (start, end) =
+                vector_range_arguments("string->vector", &args[1..], string.chars().count())?;
+
+            Ok(SteelVal::VectorV(
+                Gc::new(
+                    string
+                        .chars()
+                        .skip(start)
+                        .take(end - start)
+                        .map(SteelVal::CharV)
+                        .collect::<Vector<_>>(),
+                )
+                .into(),
+            ))
+        })
+    }
+
+    // (vector->string vector [start [end]])
+    pub fn vector_to_string() -> SteelVal {
+        SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+            if args.is_empty() || args.len() > 3 {
+                stop!(ArityMismatch => "vector->string takes between one and three arguments, found: {}", args.len())
+            }
+
+            fn collect_chars<'a>(values: impl Iterator<Item = &'a SteelVal>) -> Result<String> {
+                values
+                    .map(|value| match value {
+                        SteelVal::CharV(c) => Ok(*c),
+                        other => {
+                            stop!(TypeMismatch => "vector->string expects a vector of characters, found: {}", other)
+                        }
+                    })
+                    .collect()
+            }
+
+            let string = match &args[0] {
+                SteelVal::VectorV(v) => {
+                    let (start, end) =
+                        vector_range_arguments("vector->string", &args[1..], v.len())?;
+                    collect_chars(v.iter().skip(start).take(end - start))?
+                }
+                SteelVal::MutableVector(v) => {
+                    let ptr = v.strong_ptr();
+                    let guard = &ptr.borrow().value;
+                    let (start, end) =
+                        vector_range_arguments("vector->string", &args[1..], guard.len())?;
+                    collect_chars(guard[start..end].iter())?
+                }
+                other => {
+                    stop!(TypeMismatch => "vector->string expects a vector, found: {}", other)
+                }
+            };
+
+            Ok(SteelVal::StringV(string.into()))
+        })
+    }
+
     pub fn mut_vec_to_list() -> SteelVal {
         SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
             if args.len() != 1 {
@@ -512,24 +801,23 @@ impl VectorOperations {
             }
             let mut args = args.iter();
             match (args.next(), args.next()) {
-                (Some(vec), Some(idx)) => {
-                    if let (VectorV(vec), IntV(idx)) = (vec, idx) {
-                        if idx < &0 {
-                            stop!(TypeMismatch => "vector-ref expected a positive integer");
-                        }
-
-                        let idx: usize = *idx as usize;
+                (Some(VectorV(vec)), Some(idx)) => {
+                    let idx = index_argument("vector-ref", idx)?;
 
-                        if idx < vec.len() {
-                            Ok(vec[idx].clone())
-                        } else {
-                            let e = format!("Index out of bounds - attempted to access index: {} with length: {}", idx, vec.len());
-                            stop!(Generic => e);
-                        }
+                    if idx < vec.len() {
+                        Ok(vec[idx].clone())
                     } else {
-                        stop!(TypeMismatch => format!("vector-ref expected a vector and a number, found: {vec} and {idx}"))
+                        let e = format!(
+                            "Index out of bounds - attempted to access index: {} with length: {}",
+                            idx,
+                            vec.len()
+                        );
+                        stop!(Generic => e);
                     }
                 }
+                (Some(vec), Some(idx)) => {
+                    stop!(TypeMismatch => format!("vector-ref expected a vector and a number, found: {vec} and {idx}"))
+                }
                 _ => stop!(ArityMismatch => "vector-ref takes two arguments"),
             }
         })
@@ -675,6 +963,36 @@ impl VectorOperations {
     }
 }
 
+// Resolves the optional `[start [end]]` arguments used by the vector range functions,
+// defaulting to the entire vector.
+fn vector_range_arguments(name: &str, args: &[SteelVal], len: usize) -> Result<(usize, usize)> {
+    let index = |value: &SteelVal| -> Result<usize> {
+        match value {
+            SteelVal::IntV(i) if *i >= 0 => Ok(*i as usize),
+            other => {
+                stop!(TypeMismatch => "{} expects a non negative integer index, found: {}", name, other)
+            }
+        }
+    };
+
+    let start = args.first().map(index).transpose()?.unwrap_or(0);
+    let end = args.get(1).map(index).transpose()?.unwrap_or(len);
+
+    if start > end || end > len {
+        stop!(Generic => "{}: invalid range [{}, {}) for length {}", name, start, end, len);
+    }
+
+    Ok((start, end))
+}
+
+fn check_copy_destination(at: usize, count: usize, len: usize) -> Result<()> {
+    if at + count > len {
+        stop!(Generic => "vector-copy!: copying {} elements to offset {} would overflow the destination of length {}", count, at, len);
+    }
+
+    Ok(())
+}
+
 fn unwrap_list_of_lists(args: Vec<SteelVal>) -> Result<Vec<Vector<SteelVal>>> {
     args.iter().map(unwrap_single_list).collect()
 }
@@ -705,6 +1023,32 @@ mod vector_prim_tests {
         assert_eq!(res.unwrap(), expected);
     }
 
+    #[test]
+    fn vec_ref_test_negative_index() {
+        let args = vec![
+            vector![SteelVal::IntV(1), SteelVal::IntV(2)].into(),
+            SteelVal::IntV(-1),
+        ];
+        let res = apply_function(VectorOperations::vec_ref(), args);
+        assert_eq!(
+            res.unwrap_err().kind(),
+            crate::rerrs::ErrorKind::ContractViolation
+        );
+    }
+
+    #[test]
+    fn vec_ref_test_float_index() {
+        let args = vec![
+            vector![SteelVal::IntV(1), SteelVal::IntV(2)].into(),
+            SteelVal::NumV(1.0),
+        ];
+        let res = apply_function(VectorOperations::vec_ref(), args);
+        assert_eq!(
+            res.unwrap_err().kind(),
+            crate::rerrs::ErrorKind::ContractViolation
+        );
+    }
+
     #[test]
     fn vec_append_test_good_inputs() {
         let args = vec![
@@ -998,4 +1342,26 @@ mod vector_prim_tests {
         let expected = SteelVal::BoolV(true);
         assert_eq!(res.unwrap(), expected);
     }
+
+    #[test]
+    fn string_to_vector_range() {
+        let args = vec![
+            SteelVal::StringV("hello".into()),
+            SteelVal::IntV(1),
+            SteelVal::IntV(3),
+        ];
+        let res = apply_function(VectorOperations::string_to_vector(), args);
+        let expected: SteelVal = vector![SteelVal::CharV('e'), SteelVal::CharV('l')].into();
+        assert_eq!(res.unwrap(), expected);
+    }
+
+    #[test]
+    fn vector_to_string_non_char() {
+        let args = vec![vector![SteelVal::CharV('a'), SteelVal::IntV(1)].into()];
+        let res = apply_function(VectorOperations::vector_to_string(), args);
+        assert_eq!(
+            res.unwrap_err().kind(),
+            crate::rerrs::ErrorKind::TypeMismatch
+        );
+    }
 }
@@ -20,6 +20,7 @@ struct Repr {
     pub span: Option<Span>,
     // pub source: Option<Rc<PathBuf>>,
     pub stack_trace: Option<DehydratedStackTrace>,
+    pub exit_code: Option<i32>,
 }
 
 impl Repr {
@@ -49,6 +50,8 @@ pub enum ErrorKind {
     Parse,
     Infallible,
     Generic,
+    ResourceExhausted,
+    Exit,
 }
 
 impl ErrorKind {
@@ -66,6 +69,8 @@ impl ErrorKind {
             Parse => "E09",
             Infallible => "E10",
             Generic => "E11",
+            ResourceExhausted => "E12",
+            Exit => "E13",
         }
     }
 }
@@ -84,6 +89,7 @@ impl From<std::io::Error> for Repr {
             span: None,
             // source: None,
             stack_trace: None,
+            exit_code: None,
         }
     }
 }
@@ -108,6 +114,7 @@ impl From<Infallible> for Repr {
             span: None,
             // source: None,
             stack_trace: None,
+            exit_code: None,
         }
     }
 }
@@ -135,6 +142,7 @@ impl From<ParseError> for Repr {
             span,
             // source: source.clone(),
             stack_trace: None,
+            exit_code: None,
         }
     }
 }
@@ -174,6 +182,10 @@ impl SteelErr {
         self.repr.kind = kind;
     }
 
+    pub fn message(&self) -> &str {
+        &self.repr.message
+    }
+
     pub fn prepend_message(&mut self, message: &str) {
         self.repr.message.insert_str(0, &message)
     }
@@ -186,10 +198,24 @@ impl SteelErr {
                 span: None,
                 // source: None,
                 stack_trace: None,
+                exit_code: None,
             }),
         }
     }
 
+    /// Creates the error used by `exit` to stop evaluation. It can't be caught by exception
+    /// handlers, and hands the requested exit code back to the host.
+    pub fn exit(code: i32) -> Self {
+        let mut error = SteelErr::new(ErrorKind::Exit, format!("exit with code {code}"));
+        error.repr.exit_code = Some(code);
+        error
+    }
+
+    /// The exit code requested by `exit`, if this error came from it
+    pub fn exit_code(&self) -> Option<i32> {
+        self.repr.exit_code
+    }
+
     pub fn span(&self) -> Option<Span> {
         self.repr.span
     }
@@ -387,6 +413,11 @@ macro_rules! stop {
     // ($type:ident) => {
     //     return Err(SteelErr::new(ErrorKind::$type, None));
     // };
+    // This has to come before the plain format variant, otherwise the span would get
+    // swallowed up into the format arguments
+    ($type:ident => $fmt:expr, $($arg:expr),+ ; $span:expr) => {
+        return Err($crate::rerrs::SteelErr::new($crate::rerrs::ErrorKind::$type, format!($fmt, $($arg),+)).with_span($span))
+    };
     ($type:ident => $fmt:expr, $($arg:tt)+) => {
         return Err($crate::rerrs::SteelErr::new($crate::rerrs::ErrorKind::$type, format!($fmt, $($arg)+)))
     };
@@ -408,6 +439,9 @@ macro_rules! builtin_stop {
     // ($type:ident) => {
     //     return Err(SteelErr::new(ErrorKind::$type, None));
     // };
+    ($type:ident => $fmt:expr, $($arg:expr),+ ; $span:expr) => {
+        return Some(Err($crate::rerrs::SteelErr::new($crate::rerrs::ErrorKind::$type, format!($fmt, $($arg),+)).with_span($span)))
+    };
     ($type:ident => $fmt:expr, $($arg:tt)+) => {
         return Some(Err($crate::rerrs::SteelErr::new($crate::rerrs::ErrorKind::$type, format!($fmt, $($arg)+))))
     };
@@ -1603,11 +1603,14 @@ impl SteelVal {
             (IntV(l), IntV(r)) => l == r,
             (NumV(l), NumV(r)) => l == r,
             (BoolV(l), BoolV(r)) => l == r,
+            (CharV(l), CharV(r)) => l == r,
             (VectorV(l), VectorV(r)) => Gc::ptr_eq(&l.0, &r.0),
             (Void, Void) => true,
             (StringV(l), StringV(r)) => Rc::ptr_eq(l, r),
             (FuncV(l), FuncV(r)) => *l as usize == *r as usize,
-            (SymbolV(l), SymbolV(r)) => Rc::ptr_eq(l, r),
+            // Symbols are conceptually interned, so two symbols with the same name are
+            // the same object even if they happen to live in separate allocations
+            (SymbolV(l), SymbolV(r)) => Rc::ptr_eq(l, r) || l == r,
             (SteelVal::Custom(l), SteelVal::Custom(r)) => Gc::ptr_eq(l, r),
             (HashMapV(l), HashMapV(r)) => Gc::ptr_eq(&l.0, &r.0),
             (HashSetV(l), HashSetV(r)) => Gc::ptr_eq(&l.0, &r.0),
@@ -1627,10 +1630,26 @@ impl SteelVal {
             (MutFunc(l), MutFunc(r)) => *l as usize == *r as usize,
             (BuiltIn(l), BuiltIn(r)) => *l as usize == *r as usize,
             (MutableVector(l), MutableVector(r)) => HeapRef::ptr_eq(l, r),
+            (Pair(l), Pair(r)) => Gc::ptr_eq(l, r),
+            (CustomStruct(l), CustomStruct(r)) => Gc::ptr_eq(l, r),
+            (Boxed(l), Boxed(r)) => Gc::ptr_eq(l, r),
+            (HeapAllocated(l), HeapAllocated(r)) => HeapRef::ptr_eq(l, r),
             (BigNum(l), BigNum(r)) => Gc::ptr_eq(l, r),
             (_, _) => false,
         }
     }
+
+    /// Implements `eqv?`: numbers of the same exactness are compared by value, everything
+    /// else falls back to `eq?`.
+    pub(crate) fn eqv(&self, other: &SteelVal) -> bool {
+        match (self, other) {
+            (Rational(l), Rational(r)) => l == r,
+            (BigNum(l), BigNum(r)) => l == r,
+            (BigRational(l), BigRational(r)) => l == r,
+            (Complex(l), Complex(r)) => l == r,
+            _ => self.ptr_eq(other),
+        }
+    }
 }
 
 impl Hash for SteelVal {
@@ -178,9 +178,9 @@ impl CycleDetector {
             }
             FuncV(func) => {
                 if let Some(name) = get_function_name(*func) {
-                    write!(f, "#<function:{}>", name.name)
+                    write!(f, "#<procedure:{}>", name.name)
                 } else {
-                    write!(f, "#<function>")
+                    write!(f, "#<procedure:builtin>")
                 }
             }
             Void => write!(f, "#<void>"),
@@ -222,7 +222,13 @@ impl CycleDetector {
             }
 
             PortV(_) => write!(f, "#<port>"),
-            Closure(_) => write!(f, "#<bytecode-closure>"),
+            Closure(c) => {
+                if let Some(name) = c.name {
+                    write!(f, "#<procedure:{}>", name)
+                } else {
+                    write!(f, "#<procedure>")
+                }
+            }
             HashMapV(hm) => write!(f, "#<hashmap {:#?}>", hm.as_ref()),
             IterV(_) => write!(f, "#<iterator>"),
             HashSetV(hs) => write!(f, "#<hashset {:?}>", hs.0),
@@ -231,9 +237,9 @@ impl CycleDetector {
             StreamV(_) => write!(f, "#<stream>"),
             BoxedFunction(b) => {
                 if let Some(name) = b.name() {
-                    write!(f, "#<function:{}>", name)
+                    write!(f, "#<procedure:{}>", name)
                 } else {
-                    write!(f, "#<function>")
+                    write!(f, "#<procedure:builtin>")
                 }
             }
             ContinuationFunction(_) => write!(f, "#<continuation>"),
@@ -253,8 +259,8 @@ impl CycleDetector {
 
                 write!(f, ")")
             }
-            MutFunc(_) => write!(f, "#<function>"),
-            BuiltIn(_) => write!(f, "#<function>"),
+            MutFunc(_) => write!(f, "#<procedure:builtin>"),
+            BuiltIn(_) => write!(f, "#<procedure:builtin>"),
             ReducerV(_) => write!(f, "#<reducer>"),
             MutableVector(v) => write!(f, "{:?}", v.get()),
             SyntaxObject(s) => {
@@ -299,9 +305,9 @@ impl CycleDetector {
             }
             FuncV(func) => {
                 if let Some(name) = get_function_name(*func) {
-                    write!(f, "#<function:{}>", name.name)
+                    write!(f, "#<procedure:{}>", name.name)
                 } else {
-                    write!(f, "#<function>")
+                    write!(f, "#<procedure:builtin>")
                 }
             }
             Pair(p) => {
@@ -349,7 +355,13 @@ impl CycleDetector {
             }
 
             PortV(_) => write!(f, "#<port>"),
-            Closure(_) => write!(f, "#<bytecode-closure>"),
+            Closure(c) => {
+                if let Some(name) = c.name {
+                    write!(f, "#<procedure:{}>", name)
+                } else {
+                    write!(f, "#<procedure>")
+                }
+            }
             HashMapV(hm) => write!(f, "#<hashmap {:#?}>", hm.as_ref()),
             IterV(_) => write!(f, "#<iterator>"),
             HashSetV(hs) => write!(f, "#<hashset {:?}>", hs.0),
@@ -359,9 +371,9 @@ impl CycleDetector {
             StreamV(_) => write!(f, "#<stream>"),
             BoxedFunction(b) => {
                 if let Some(name) = b.name() {
-                    write!(f, "#<function:{}>", name)
+                    write!(f, "#<procedure:{}>", name)
                 } else {
-                    write!(f, "#<function>")
+                    write!(f, "#<procedure:builtin>")
                 }
             }
             ContinuationFunction(_) => write!(f, "#<continuation>"),
@@ -388,8 +400,8 @@ impl CycleDetector {
                 write!(f, ")")
             }
             // write!(f, "#<list {:?}>", l),
-            MutFunc(_) => write!(f, "#<function>"),
-            BuiltIn(_) => write!(f, "#<function>"),
+            MutFunc(_) => write!(f, "#<procedure:builtin>"),
+            BuiltIn(_) => write!(f, "#<procedure:builtin>"),
             ReducerV(_) => write!(f, "#<reducer>"),
             MutableVector(v) => write!(f, "{:?}", v.get()),
             SyntaxObject(s) => {
@@ -1727,8 +1739,16 @@ impl<'a> RecursiveEqualityHandler<'a> {
                         continue;
                     }
 
-                    self.left.visit_mutable_vector(l);
-                    self.right.visit_mutable_vector(r);
+                    let (l, r) = (l.get(), r.get());
+
+                    if l.len() != r.len() {
+                        return false;
+                    }
+
+                    for (left, right) in l.into_iter().zip(r) {
+                        self.left.push_back(left);
+                        self.right.push_back(right);
+                    }
 
                     continue;
                 }
@@ -275,6 +275,104 @@
                 (lambda (this value) (setter-proto this ,(list-ref field 1) value))))
        (enumerate 0 '() fields)))
 
+;; R7RS records, in the form of:
+;; `(define-record-type <type-name> (<constructor> field ...) <predicate> (field accessor [modifier]) ...)`
+;;
+;; Unlike `struct`, the constructor, predicate, accessors and modifiers are bound to exactly
+;; the names given. Only fields that are given a modifier can be mutated, and fields left out
+;; of the constructor start out as void.
+(#%define-syntax (define-record-type expr)
+                 (define unwrapped (map syntax->datum (syntax-e expr)))
+                 (when (< (length unwrapped) 4)
+                   (error! "define-record-type expects a type name, a constructor and a predicate"))
+                 (define result
+                   (record-type-impl (second unwrapped)
+                                     (third unwrapped)
+                                     (list-ref unwrapped 3)
+                                     (list-tail unwrapped 4)))
+                 (syntax/loc result
+                   (syntax-span expr)))
+
+(define (record-type-impl type-name constructor predicate field-specs)
+  (when (not (symbol? type-name))
+    (error! "define-record-type expects an identifier as the type name, found " type-name))
+
+  (when (not (and (list? constructor) (not (empty? constructor))))
+    (error! "define-record-type expects a constructor of the form (name field ...), found "
+            constructor))
+
+  (when (not (symbol? predicate))
+    (error! "define-record-type expects an identifier as the predicate, found " predicate))
+
+  (when (contains? (lambda (spec)
+                     (not (and (list? spec) (>= (length spec) 2) (<= (length spec) 3))))
+                   field-specs)
+    (error! "define-record-type expects fields of the form (field accessor [modifier]), found "
+            field-specs))
+
+  (define fields (map car field-specs))
+  (define field-count (length fields))
+  (define constructor-name (car constructor))
+  (define constructor-fields (cdr constructor))
+  (define mutable-specs (filter (lambda (spec) (= (length spec) 3)) field-specs))
+
+  (define (mutable-field? field)
+    (contains? (lambda (spec) (equal? (car spec) field)) mutable-specs))
+
+  (when (contains? (lambda (field) (not (member field fields))) constructor-fields)
+    (error! "define-record-type constructor refers to an unknown field: " constructor))
+
+  (define struct-options-name (concat-symbols '___ type-name '-options___))
+
+  `(begin
+     (define ,struct-options-name
+       (hash '#:mutable
+             ,(not (empty? mutable-specs))
+             '#:transparent
+             #f
+             '#:fields
+             (quote ,fields)
+             '#:name
+             (quote ,type-name)
+             '#:printer
+             #f))
+     (define ,type-name 'uninitialized)
+     (define ,constructor-name 'uninitialized)
+     (define ,predicate 'uninitialized)
+     ,@(map (lambda (spec) `(define ,(second spec) 'uninitialized)) field-specs)
+     ,@(map (lambda (spec) `(define ,(third spec) 'uninitialized)) mutable-specs)
+
+     (%plain-let
+      ([prototypes (make-struct-type (quote ,type-name) ,field-count)])
+      (%plain-let
+       ([struct-type-descriptor (list-ref prototypes 0)] [constructor-proto (list-ref prototypes 1)]
+                                                         [predicate-proto (list-ref prototypes 2)]
+                                                         [getter-proto (list-ref prototypes 3)])
+       (set! ,type-name struct-type-descriptor)
+       (#%vtable-update-entry! struct-type-descriptor #f ,struct-options-name)
+       (set! ,constructor-name
+             (lambda ,constructor-fields
+               (constructor-proto ,@(map (lambda (field)
+                                           (let ([value (if (member field constructor-fields)
+                                                            field
+                                                            'void)])
+                                             (if (mutable-field? field) `(#%box ,value) value)))
+                                         fields))))
+       (set! ,predicate predicate-proto)
+       ,@(map (lambda (spec-index)
+                (let ([spec (car spec-index)] [index (list-ref spec-index 1)])
+                  (if (mutable-field? (car spec))
+                      `(set! ,(second spec) (lambda (this) (#%unbox (getter-proto this ,index))))
+                      `(set! ,(second spec) (lambda (this) (getter-proto this ,index))))))
+              (enumerate 0 '() field-specs))
+       ,@(map (lambda (spec-index)
+                (let ([spec (car spec-index)] [index (list-ref spec-index 1)])
+                  `(set! ,(third spec)
+                         (lambda (this value) (#%set-box! (getter-proto this ,index) value)))))
+              (filter (lambda (spec-index) (mutable-field? (car (car spec-index))))
+                      (enumerate 0 '() field-specs)))
+       void))))
+
 (define (%make-memoize f)
   (lambda n
     (let ([previous-value (%memo-table-ref %memo-table f n)])
@@ -287,11 +385,6 @@
             (%memo-table-set! %memo-table f n new-value)
             new-value)))))
 
-(define *gensym-counter* 0)
-(define (gensym)
-  (set! *gensym-counter* (+ 1 *gensym-counter*))
-  (string->symbol (string-append "##gensym" (to-string *gensym-counter*))))
-
 ;; TODO: @Matt -> for whatever reason, using ~> plus (lambda (x) ...) generates a stack overflow... look into that
 (define (make-unreadable symbol)
   (~>> symbol (symbol->string) (string-append "##") (string->symbol)))
@@ -261,7 +261,31 @@
                                 introduced-identifiers)
                #f)]
 
-         [else (error "list pattern must start with `list - found " (car pattern))])]
+         ;; Destructure a pair, matching the car and the cdr separately. This also
+         ;; matches non empty lists, with the cdr being the rest of the list
+         [(and (equal? (car pattern) 'cons) (= (length pattern) 3))
+          (define car-input (gensym-ident 'car-input))
+          (define cdr-input (gensym-ident 'cdr-input))
+          `(if (pair? ,input)
+               (let ([,car-input (car ,input)] [,cdr-input (cdr ,input)])
+                 ,(match-p-syntax (cadr pattern)
+                                  car-input
+                                  (match-p-syntax (caddr pattern)
+                                                  cdr-input
+                                                  final-body-expr
+                                                  (+ 1 depth)
+                                                  bound-vars
+                                                  check-var?
+                                                  0
+                                                  introduced-identifiers)
+                                  (+ 1 depth)
+                                  bound-vars
+                                  check-var?
+                                  0
+                                  introduced-identifiers))
+               #f)]
+
+         [else (error "list pattern must start with `list or `cons - found " (car pattern))])]
 
       [(and (list? pattern) (not (null? pattern)) (starts-with-many? pattern))
        (if (null? (cddr pattern))
@@ -426,6 +450,9 @@
 
 ;; TODO add case for the remaining - when there is no else case given
 ;; and it should just error out
+;;
+;; A successful match produces the clause result wrapped in a list, so that
+;; a body evaluating to #f is not mistaken for a failed match.
 (define-syntax match-dispatch
   (syntax-rules (else)
     ;; Explicitly giving an else case
@@ -439,19 +466,19 @@
      (let ([match? (single-match expr
                                  p1
                                  (if when-expr
-                                     (begin
-                                       e2 ...)
+                                     (list (begin
+                                             e2 ...))
                                      #f))])
-       (if (not (equal? #f match?)) match? (match-dispatch expr c0 c1 ...)))]
+       (if (not (equal? #f match?)) (car match?) (match-dispatch expr c0 c1 ...)))]
 
     ;; Generic recursive case
     [(match-dispatch expr [p1 e2 ...] c0 c1 ...)
      (let ([match? (single-match expr
                                  p1
-                                 (begin
-                                   e2 ...))])
+                                 (list (begin
+                                         e2 ...)))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
 
            (match-dispatch expr c0 c1 ...)))]
 
@@ -459,11 +486,11 @@
      (let ([match? (single-match expr
                                  p1
                                  (if when-expr
-                                     (begin
-                                       e2 ...)
+                                     (list (begin
+                                             e2 ...))
                                      #f))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
            (error! "Unable to match expression: " expr " to any of the given patterns")))]
 
     ;; When there isn't an else case given, the last case
@@ -471,10 +498,10 @@
     [(match-dispatch expr (p1 e2 ...))
      (let ([match? (single-match expr
                                  p1
-                                 (begin
-                                   e2 ...))])
+                                 (list (begin
+                                         e2 ...)))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
            (error! "Unable to match expression: " expr " to any of the given patterns")))]))
 
 (define-syntax match-syntax-dispatch
@@ -490,19 +517,19 @@
      (let ([match? (single-match-syntax expr
                                         p1
                                         (if when-expr
-                                            (begin
-                                              e2 ...)
+                                            (list (begin
+                                                    e2 ...))
                                             #f))])
-       (if (not (equal? #f match?)) match? (match-syntax-dispatch expr c0 c1 ...)))]
+       (if (not (equal? #f match?)) (car match?) (match-syntax-dispatch expr c0 c1 ...)))]
 
     ;; Generic recursive case
     [(match-syntax-dispatch expr [p1 e2 ...] c0 c1 ...)
      (let ([match? (single-match-syntax expr
                                         p1
-                                        (begin
-                                          e2 ...))])
+                                        (list (begin
+                                                e2 ...)))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
 
            (match-syntax-dispatch expr c0 c1 ...)))]
 
@@ -510,11 +537,11 @@
      (let ([match? (single-match-syntax expr
                                         p1
                                         (if when-expr
-                                            (begin
-                                              e2 ...)
+                                            (list (begin
+                                                    e2 ...))
                                             #f))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
            (error! "Unable to match expression: " expr " to any of the given patterns")))]
 
     ;; When there isn't an else case given, the last case
@@ -522,10 +549,10 @@
     [(match-syntax-dispatch expr (p1 e2 ...))
      (let ([match? (single-match-syntax expr
                                         p1
-                                        (begin
-                                          e2 ...))])
+                                        (list (begin
+                                                e2 ...)))])
        (if (not (equal? #f match?))
-           match?
+           (car match?)
            (error! "Unable to match expression: " expr " to any of the given patterns")))]))
 
 (define-syntax match-define
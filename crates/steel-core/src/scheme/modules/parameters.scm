@@ -7,18 +7,34 @@
 
 ;;;;;; Parameters ;;;;;
 
-(struct Parameter (getter value)
+(struct Parameter (getter value converter)
   #:mutable
   #:printer (lambda (obj printer-function) (simple-display "<procedure:parameter-procedure>"))
   #:prop:procedure 0)
 
-(define (make-parameter value)
-  (define param (Parameter 'uninitialized value))
+;;@doc
+;; Creates a parameter holding `value`. If a `converter` procedure is given, it is
+;; applied to the initial value, and to every value the parameter is later set to,
+;; including each `parameterize` binding. The value restored when a `parameterize`
+;; exits is not converted again.
+;;
+;; (make-parameter value [converter]) -> parameter?
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (define width (make-parameter "10" string->number))
+;; (width) ;; => 10
+;; (parameterize ([width "20"]) (width)) ;; => 20
+;; ```
+(define (make-parameter value . converter)
+  (define convert (if (null? converter) (lambda (x) x) (car converter)))
+  (define param (Parameter 'uninitialized (convert value) convert))
 
   (set-Parameter-getter! param
                          (case-lambda
                            [() (Parameter-value param)]
-                           [(new-value) (set-Parameter-value! param new-value)]))
+                           [(new-value) (set-Parameter-value! param (convert new-value))]))
 
   param)
 
@@ -32,9 +48,9 @@
     [(parameterize ([var val] rest ...)
        body ...)
 
-     (let ([old-value (var)])
+     (let ([old-value (var)] [new-value ((Parameter-converter var) val)])
 
-       (dynamic-wind (lambda () (set-Parameter-value! var val))
+       (dynamic-wind (lambda () (set-Parameter-value! var new-value))
                      (lambda ()
                        (parameterize (rest ...)
                          body ...))
@@ -44,15 +60,54 @@
 
 (provide current-input-port
          current-output-port
+         read-char
+         peek-char
+         read-line
+         read-string
          simple-display
          simple-displayln
          newline
          write-char
-         write)
+         write
+         writeln)
 
 (define current-input-port (make-parameter (#%default-input-port)))
 (define current-output-port (make-parameter (#%default-output-port)))
 
+(define read-char
+  (case-lambda
+    [() (raw-read-char (current-input-port))]
+    [(port) (raw-read-char port)]))
+
+(define peek-char
+  (case-lambda
+    [() (raw-peek-char (current-input-port))]
+    [(port) (raw-peek-char port)]))
+
+;; Reads the next line from the port, without the trailing newline. Returns the
+;; eof object once the port is exhausted.
+(define read-line
+  (case-lambda
+    [() (read-line (current-input-port))]
+    [(port)
+     (let ([line (read-line-from-port port)])
+       (if (string? line) (trim-end-matches line "\n") line))]))
+
+;; Reads up to `k` characters from the port into a string. Fewer characters are returned
+;; if the port runs out, and the eof object if there was nothing left to read.
+(define read-string
+  (case-lambda
+    [(k) (read-string k (current-input-port))]
+    [(k port)
+     (let loop ([remaining k] [chars '()])
+       (if (<= remaining 0)
+           (list->string (reverse chars))
+           (let ([next (raw-read-char port)])
+             (cond
+               [(not (eof-object? next)) (loop (- remaining 1) (cons next chars))]
+               [(null? chars) next]
+               [else (list->string (reverse chars))]))))]))
+
 (define (simple-display x)
   (raw-write-string (current-output-port) x))
 
@@ -69,13 +124,26 @@
 (define (write-char char port)
   (raw-write-char port char))
 
-(define (write obj port)
-  (raw-write port obj))
+(define write
+  (case-lambda
+    [(obj) (raw-write (current-output-port) obj)]
+    [(obj port) (raw-write port obj)]))
+
+(define writeln
+  (case-lambda
+    [(obj)
+     (write obj)
+     (newline)]
+    [(obj port)
+     (write obj port)
+     (newline port)]))
 
 ;;;;;;;;;;;;;;;;;;;;; Port functions ;;;;;;;;;;;;;;;;;;;;;
 
 (provide call-with-output-string
-         with-output-to-string)
+         with-output-to-string
+         call-with-input-string
+         with-input-from-string)
 
 (define (call-with-output-string proc)
   (define output-string (open-output-string))
@@ -87,6 +155,17 @@
                              (parameterize ([current-output-port p])
                                (proc)))))
 
+(define (call-with-input-string string proc)
+  (proc (open-input-string string)))
+
+;; Calls `thunk` with the current input port reading from `string`, so `read`, `read-line`
+;; and `read-char` consume the string. The previous input port is restored afterwards.
+(define (with-input-from-string string thunk)
+  (call-with-input-string string
+                          (lambda (p)
+                            (parameterize ([current-input-port p])
+                              (thunk)))))
+
 ;;;;;;;;;;;;;;;;;;;;; Dynamic Wind ;;;;;;;;;;;;;;;;;;;;;;;
 
 (define winders '())
@@ -30,16 +30,21 @@
          curry2
          foldl
          map
+         for-each
          foldr
          unfold
          fold
          reduce
+         reduce-right
          max
          min
          mem-helper
          member
+         memv
+         memq
          contains?
          assq
+         assv
          assoc
          filter
          even-rec?
@@ -51,13 +56,32 @@
          drop
          slice
          flatten
+         zip
+         unzip1
+         unzip2
+         vector-binary-search
+         vector-index
+         vector-find
+         vector-sort
+         vector-sort!
+         hash-ref!
+         hash-for-each
+         plist-get
+         plist-put
+         alist-update
+         alist-delete
+         list-tabulate
+         build-list
          *meta-continuation*
          *abort
          *reset
          *shift
          force
          values
-         call-with-values)
+         call-with-values
+         list->values
+         vector->values
+         values->list)
 
 ; (define-syntax steel/base
 ;   (syntax-rules ()
@@ -181,26 +205,68 @@
     [(and x y) (if x y #f)]
     [(and x y ...) (and x (and y ...))]))
 
+;; Both of these expand to `if` on the guard itself, so they agree with `if` on what is
+;; truthy. The skipped branch is a one armed `if`, which evaluates to void exactly the way
+;; `if` does, regardless of what `void` is bound to locally.
 (define-syntax when
   (syntax-rules ()
     [(when a
        b ...)
      (if a
          (begin
-           b ...)
-         void)]))
+           b ...))]))
 
 (define-syntax unless
   (syntax-rules ()
     [(unless a
        b ...)
      (if a
-         void
+         (if #f #f)
          (begin
            b ...))]))
 
+;; Binds `name` to the value of `test` and runs the consequent with it in scope, but only
+;; when that value is truthy. The binding is not visible in the alternative.
+;;
+;; (if-let (x (assoc 'b '((a 1) (b 2)))) (cadr x) 'missing) ;; => 2
+(define-syntax if-let
+  (syntax-rules ()
+    [(if-let (name test)
+       then)
+     (let ([t test])
+       (if t
+           (let ([name t])
+             then)))]
+    [(if-let (name test)
+       then
+       alternative)
+     (let ([t test])
+       (if t
+           (let ([name t])
+             then)
+           alternative))]))
+
+;; Like `if-let`, but runs a body of expressions and has no alternative.
+;;
+;; (when-let (x (member 2 '(1 2 3))) (displayln x) (length x)) ;; => 2
+(define-syntax when-let
+  (syntax-rules ()
+    [(when-let (name test)
+       body ...)
+     (let ([t test])
+       (when t
+         (let ([name t])
+           body ...)))]))
+
 (define-syntax cond
   (syntax-rules (else =>)
+    [(cond) (if #f #f)]
+    [(cond
+       [else
+        e1 ...]
+       c1
+       c2 ...)
+     (syntax-error "else clause must be the last clause in cond")]
     [(cond
        [else
         =>
@@ -213,20 +279,32 @@
      (begin
        e1 ...)]
     [(cond
-       [e1
-        e2 ...])
-     (when e1
-       e2 ...)]
+       [e1])
+     (let ([t e1])
+       (if t t))]
+    [(cond
+       [e1]
+       c1 ...)
+     (let ([t e1])
+       (if t
+           t
+           (cond
+             c1 ...)))]
     [(cond
        [e1
         =>
-        e2 ...]
+        f]
        c1 ...)
-     (if e1
-         (begin
-           e2 ...)
-         (cond
-           c1 ...))]
+     (let ([t e1])
+       (if t
+           (f t)
+           (cond
+             c1 ...)))]
+    [(cond
+       [e1
+        e2 ...])
+     (when e1
+       e2 ...)]
     [(cond
        [e1
         e2 ...]
@@ -237,13 +315,19 @@
          (cond
            c1 ...))]))
 
+;; Datums are compared against the key with `eqv?`, so `(case 2.0 [(2) ...])` does not match
 (define-syntax case
-  (syntax-rules (else)
+  (syntax-rules (else =>)
     [(case (key ...)
        clauses ...)
      (let ([atom-key (key ...)])
        (case atom-key
          clauses ...))]
+    [(case key
+       [else
+        =>
+        proc])
+     (proc key)]
     [(case key
        [else
         result1
@@ -251,11 +335,28 @@
      (begin
        result1
        result2 ...)]
+    [(case key
+       [(atoms ...)
+        =>
+        proc])
+     (when (memv key '(atoms ...))
+       (proc key))]
+    [(case key
+       [(atoms ...)
+        =>
+        proc]
+       clause
+       clauses ...)
+     (if (memv key '(atoms ...))
+         (proc key)
+         (case key
+           clause
+           clauses ...))]
     [(case key
        [(atoms ...)
         result1
         result2 ...])
-     (when (member key '(atoms ...))
+     (when (memv key '(atoms ...))
        (begin
          result1
          result2 ...))]
@@ -263,7 +364,7 @@
     ; [(case key
     ;        ((atoms ...) result1 ...)
     ;        clause clauses ...)
-    ;      (if (member key '(atoms ...))
+    ;      (if (memv key '(atoms ...))
     ;          (begin result1 ...)
     ;          (case key clause clauses ...))]
 
@@ -273,7 +374,7 @@
         result2 ...]
        clause
        clauses ...)
-     (if (member key '(atoms ...))
+     (if (memv key '(atoms ...))
          (begin
            result1
            result2 ...)
@@ -482,43 +583,132 @@
 (define curry2 (lambda (func arg1) (lambda (arg2 arg3) (func arg1 arg2 arg3))))
 ; (define compose (lambda (f g) (lambda (arg) (f (g arg)))))
 
-(define (foldl func accum lst)
-  (if (null? lst)
-      accum
-      (foldl func
-             (func (car lst) accum) ; here's the change
-             (cdr lst))))
+;;@doc
+;; Folds `func` over `lst` from the left, calling `(func elem accum)` for each element.
+;; Given several lists, `func` receives the elements at the same position in each list
+;; followed by the accumulator, and folding stops at the end of the shortest list.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (foldl cons '() '(1 2 3)) ;; => '(3 2 1)
+;; (foldl (lambda (x y acc) (+ x y acc)) 0 '(1 2 3) '(10 20)) ;; => 33
+;; ```
+(define (foldl func accum lst . lsts)
+  (if (null? lsts)
+      (let loop ([accum accum] [lst lst])
+        (if (null? lst)
+            accum
+            (loop (func (car lst) accum) ; here's the change
+                  (cdr lst))))
+      (let loop ([accum accum] [lsts (cons lst lsts)])
+        (if (contains? null? lsts)
+            accum
+            (loop (apply func (append (map car lsts) (list accum))) (map cdr lsts))))))
 
+;;@doc
+;; Applies `func` to each element of `lst`, returning a new list of the results. Given
+;; several lists, `func` is applied to the elements at the same position in each list,
+;; and the result is as long as the shortest list.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (map add1 '(1 2 3)) ;; => '(2 3 4)
+;; (map + '(1 2 3) '(10 20)) ;; => '(11 22)
+;; ```
 (define (map func lst . lsts)
-
   (cond
     [(null? lst) '()]
     [(null? lsts) (transduce lst (mapping func) (into-list))]
     [else
-     =>
-     (define (crunch composer remaining-lists)
-       (if (null? remaining-lists)
-           composer
-           (crunch (compose composer (zipping (car remaining-lists))) (cdr remaining-lists))))
-     (if (null? lsts)
-         (map func lst)
-         ;; Handle the case for many lists
-         (let ([composed-transducer (crunch (compose) lsts)])
-           (transduce lst composed-transducer (mapping (lambda (x) (apply func x))) (into-list))))]))
+     (let loop ([lsts (cons lst lsts)] [acc '()])
+       (if (contains? null? lsts)
+           (reverse acc)
+           (loop (map cdr lsts) (cons (apply func (map car lsts)) acc))))]))
 
 ; (if (null? lst)
 ;     '()
 ;     (transduce lst (mapping func) (into-list))))
+;;@doc
+;; Applies `func` to each element of `lst` (or, given several lists, to the elements at the
+;; same position in each list) for its side effects, stopping at the end of the shortest list.
+;; Iterates in constant stack space, so arbitrarily long lists are fine.
+;;
+;; (for-each displayln '(1 2 3))
+(define (for-each func lst . lsts)
+  (if (null? lsts)
+      (let loop ([lst lst])
+        (if (null? lst)
+            (void)
+            (begin
+              (func (car lst))
+              (loop (cdr lst)))))
+      (let loop ([lsts (cons lst lsts)])
+        (if (contains? null? lsts)
+            (void)
+            (begin
+              (apply func (transduce lsts (mapping car) (into-list)))
+              (loop (transduce lsts (mapping cdr) (into-list))))))))
 
-(define foldr
-  (lambda (func accum lst) (if (null? lst) accum (func (car lst) (foldr func accum (cdr lst))))))
+;;@doc
+;; Folds `func` over `lst` from the right, calling `(func elem accum)` for each element.
+;; Like `foldl`, several lists may be given, and folding stops at the end of the shortest one.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (foldr cons '() '(1 2 3)) ;; => '(1 2 3)
+;; (foldr (lambda (x y acc) (cons (+ x y) acc)) '() '(1 2 3) '(10 20)) ;; => '(11 22)
+;; ```
+(define (foldr func accum lst . lsts)
+  (if (null? lsts)
+      (let loop ([lst lst])
+        (if (null? lst)
+            accum
+            (func (car lst) (loop (cdr lst)))))
+      (let loop ([lsts (cons lst lsts)])
+        (if (contains? null? lsts)
+            accum
+            (apply func (append (map car lsts) (list (loop (map cdr lsts)))))))))
 
 (define unfold
   (lambda (func init pred)
     (if (pred init) (cons init '()) (cons init (unfold func (func init) pred)))))
 
-(define fold (lambda (f a l) (foldl f a l)))
-(define reduce (lambda (f a l) (fold f a l)))
+(define (fold f a l . ls)
+  (if (null? ls)
+      (foldl f a l)
+      (apply foldl (cons f (cons a (cons l ls))))))
+;;@doc
+;; Reduces `lst` with `f`, using the first element as the seed rather than a separate
+;; initial value. Returns `ridentity` if the list is empty.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (reduce + 0 '(1 2 3)) ;; => 6
+;; (reduce + 0 '(10)) ;; => 10
+;; (reduce + 0 '()) ;; => 0
+;; ```
+(define (reduce f ridentity lst)
+  (if (null? lst) ridentity (foldl f (car lst) (cdr lst))))
+
+;;@doc
+;; Right associative version of `reduce`, so `(reduce-right f ridentity (list x1 x2 ... xn))`
+;; returns `(f x1 (f x2 ... (f xn-1 xn)))`. Returns `ridentity` if the list is empty.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (reduce-right append '() '((1 2) (3) (4 5))) ;; => '(1 2 3 4 5)
+;; (reduce-right + 0 '()) ;; => 0
+;; ```
+(define (reduce-right f ridentity lst)
+  (if (null? lst)
+      ridentity
+      (let loop ([head (car lst)] [tail (cdr lst)])
+        (if (null? tail) head (f head (loop (car tail) (cdr tail)))))))
 
 (define max (lambda (x . num-list) (fold (lambda (y z) (if (> y z) y z)) x (cons 0 num-list))))
 (define min
@@ -538,6 +728,27 @@
       [(equal? x (car los)) los]
       [else (member x (cdr los))])))
 
+;;@doc
+;; Like `member`, but compares elements with `eqv?`, so numbers and characters are matched
+;; by value while other objects must be the same object.
+;;
+;; (memv 101 '(100 101 102)) ;; => '(101 102)
+(define (memv x los)
+  (cond
+    [(null? los) #f]
+    [(eqv? x (car los)) los]
+    [else (memv x (cdr los))]))
+
+;;@doc
+;; Like `member`, but compares elements with `eq?`.
+;;
+;; (memq 'c '(a b c d)) ;; => '(c d)
+(define (memq x los)
+  (cond
+    [(null? los) #f]
+    [(eq? x (car los)) los]
+    [else (memq x (cdr los))]))
+
 (define (contains? pred? lst)
   ; (displayln lst)
   (cond
@@ -548,7 +759,15 @@
 ;; TODO come back to this
 (define assq (lambda (obj alist) (fold (mem-helper (curry eq? obj) car) #f alist)))
 
-;; (define assv (lambda (obj alist)     (fold (mem-helper (curry eqv? obj) car) #f alist)))
+;;@doc
+;; Like `assoc`, but compares keys with `eqv?`.
+;;
+;; (assv 2 '((1 one) (2 two))) ;; => '(2 two)
+(define (assv thing alist)
+  (cond
+    [(null? alist) #f]
+    [(eqv? (car (car alist)) thing) (car alist)]
+    [else (assv thing (cdr alist))]))
 ; (define assoc (lambda (obj alist) (fold (mem-helper (curry equal? obj) car) #f alist)))
 
 ; (define assoc )
@@ -613,6 +832,268 @@
     [(list? lst) (append (flatten (car lst)) (flatten (cdr lst)))]
     [else (list lst)]))
 
+;;@doc
+;; Combines the given lists element-wise into a list of lists, stopping at the shortest list.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (zip '(1 2 3) '(a b c)) ;; => '((1 a) (2 b) (3 c))
+;; (zip '(1 2 3) '(a b)) ;; => '((1 a) (2 b))
+;; ```
+(define (zip lst . lsts)
+  (define (loop lists acc)
+    (if (contains? null? lists) (reverse acc) (loop (map cdr lists) (cons (map car lists) acc))))
+  (loop (cons lst lsts) '()))
+
+;;@doc
+;; Returns the first element of each list in `lst`.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (unzip1 '((1 a) (2 b) (3 c))) ;; => '(1 2 3)
+;; ```
+(define (unzip1 lst)
+  (map car lst))
+
+;;@doc
+;; Returns two values, the first elements and the second elements of each list in `lst`.
+;; This is the inverse of `zip` on two lists.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (call-with-values (lambda () (unzip2 '((1 a) (2 b)))) list) ;; => '((1 2) (a b))
+;; ```
+(define (unzip2 lst)
+  (values (map car lst) (map cadr lst)))
+;;@doc
+;; Searches the sorted vector `vec` for `value`, returning the index of a matching
+;; element or `#false` if there is none. `cmp` is called as `(cmp element value)` and
+;; must return a negative number, zero, or a positive number when the element is
+;; less than, equal to, or greater than `value`. Only O(log n) comparisons are made.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (vector-binary-search (vector 1 3 5 7) 5 -) ;; => 2
+;; (vector-binary-search (vector 1 3 5 7) 4 -) ;; => #false
+;; ```
+(define (vector-binary-search vec value cmp)
+  (let loop ([lo 0] [hi (- (vector-length vec) 1)])
+    (if (> lo hi)
+        #f
+        (let* ([mid (quotient (+ lo hi) 2)] [order (cmp (vector-ref vec mid) value)])
+          (cond
+            [(zero? order) mid]
+            [(< order 0) (loop (+ mid 1) hi)]
+            [else (loop lo (- mid 1))])))))
+
+;;@doc
+;; Returns the index of the first element of `vec` for which `pred` returns a true
+;; value, or `#false` if there is none.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (vector-index even? (vector 1 3 4 6)) ;; => 2
+;; (vector-index even? (vector 1 3 5)) ;; => #false
+;; ```
+(define (vector-index pred vec)
+  (let ([len (vector-length vec)])
+    (let loop ([i 0])
+      (cond
+        [(= i len) #f]
+        [(pred (vector-ref vec i)) i]
+        [else (loop (+ i 1))]))))
+
+;;@doc
+;; Returns the first element of `vec` for which `pred` returns a true value, or
+;; `#false` if there is none.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (vector-find even? (vector 1 3 4 6)) ;; => 4
+;; (vector-find even? (vector 1 3 5)) ;; => #false
+;; ```
+(define (vector-find pred vec)
+  (let ([index (vector-index pred vec)])
+    (if index (vector-ref vec index) #f)))
+
+(define (merge-sorted-lists left right less?)
+  (let loop ([left left] [right right] [acc '()])
+    (cond
+      [(null? left) (append (reverse acc) right)]
+      [(null? right) (append (reverse acc) left)]
+      ;; Only take from the right when it's strictly smaller, which keeps the sort stable
+      [(less? (car right) (car left)) (loop left (cdr right) (cons (car right) acc))]
+      [else (loop (cdr left) right (cons (car left) acc))])))
+
+(define (merge-sort-list lst less?)
+  (let ([len (length lst)])
+    (if (< len 2)
+        lst
+        (let ([half (quotient len 2)])
+          (merge-sorted-lists (merge-sort-list (take lst half) less?)
+                              (merge-sort-list (drop lst half) less?)
+                              less?)))))
+
+;;@doc
+;; Sorts the mutable vector `vec` in place, so that `(less? a b)` holds for every
+;; element `a` that comes before an element `b` that isn't equal to it. The sort is stable.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (define vec (mutable-vector 3 1 2))
+;; (vector-sort! vec <)
+;; vec ;; => '#(1 2 3)
+;; ```
+(define (vector-sort! vec less?)
+  (let loop ([sorted (merge-sort-list (transduce vec (into-list)) less?)] [i 0])
+    (unless (null? sorted)
+      (vector-set! vec i (car sorted))
+      (loop (cdr sorted) (+ i 1)))))
+
+;;@doc
+;; Returns a new vector with the elements of `vec` sorted by `less?`, leaving `vec`
+;; unchanged. Like `vector-sort!`, the sort is stable. Mutable vectors produce a new
+;; mutable vector, and immutable vectors a new immutable vector.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (vector-sort (vector 3 1 2) <) ;; => '#(1 2 3)
+;; ```
+(define (vector-sort vec less?)
+  (let ([sorted (merge-sort-list (transduce vec (into-list)) less?)])
+    (if (mutable-vector? vec) (apply mutable-vector sorted) (apply vector sorted))))
+
+;;@doc
+;; Returns the value for `key` in the mutable hash table `table`. If the key is missing,
+;; `default` is inserted for it and returned. When `default` is a procedure, it is only
+;; called, with no arguments, if the key is missing and its result is what gets inserted.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (define table (make-hash))
+;; (hash-ref! table 'a 10) ;; => 10
+;; (hash-ref! table 'a (lambda () 20)) ;; => 10
+;; ```
+(define (hash-ref! table key default)
+  (if (hash-table-contains? table key)
+      (hash-table-ref table key)
+      (let ([value (if (procedure? default) (default) default)])
+        (hash-table-set! table key value)
+        value)))
+
+;;@doc
+;; Calls `proc` with each key and value in the hash map `map`. When `sorted?` is true,
+;; the entries are visited in the deterministic key order used by `hash->list`.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (hash-for-each (hash 'b 2 'a 1) (lambda (k v) (displayln k)) #true) ;; prints a, then b
+;; ```
+(define (hash-for-each map proc . sorted)
+  (for-each (lambda (entry) (proc (car entry) (cdr entry)))
+            (hash->list map (and (not (null? sorted)) (car sorted)))))
+
+;;@doc
+;; Returns the value following `key` in the property list `plist`, a flat list of
+;; alternating keys and values. Keys are compared with `eq?`. If the key is missing,
+;; `default` is returned, or `#false` if no default is given.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (plist-get '(a 1 b 2) 'b) ;; => 2
+;; (plist-get '(a 1 b 2) 'c) ;; => #false
+;; (plist-get '(a 1 b 2) 'c 0) ;; => 0
+;; ```
+(define (plist-get plist key . default)
+  (let loop ([rest plist])
+    (cond
+      [(or (null? rest) (null? (cdr rest))) (if (null? default) #f (car default))]
+      [(eq? (car rest) key) (cadr rest)]
+      [else (loop (cddr rest))])))
+
+;;@doc
+;; Returns a new property list where `key` is associated with `value`. An existing entry
+;; for `key` is replaced in place, otherwise the entry is added to the end. The original
+;; list is left unchanged.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (plist-put '(a 1 b 2) 'a 10) ;; => '(a 10 b 2)
+;; (plist-put '(a 1) 'b 2) ;; => '(a 1 b 2)
+;; ```
+(define (plist-put plist key value)
+  (let loop ([rest plist] [seen '()])
+    (cond
+      [(or (null? rest) (null? (cdr rest))) (append (reverse seen) (list key value))]
+      [(eq? (car rest) key) (append (reverse seen) (cons key (cons value (cddr rest))))]
+      [else (loop (cddr rest) (cons (cadr rest) (cons (car rest) seen)))])))
+
+;;@doc
+;; Returns a new association list where `key` is associated with `value`, comparing keys
+;; with `equal?` like `assoc`. The first entry for `key` is replaced with `(key . value)` in
+;; place, otherwise the entry is added to the end. The original list is left unchanged.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (alist-update (list (cons 'a 1) (cons 'b 2)) 'a 10) ;; => '((a . 10) (b . 2))
+;; (alist-update (list (cons 'a 1)) 'b 2) ;; => '((a . 1) (b . 2))
+;; ```
+(define (alist-update alist key value)
+  (let loop ([rest alist] [seen '()])
+    (cond
+      [(null? rest) (append (reverse seen) (list (cons key value)))]
+      [(equal? (car (car rest)) key) (append (reverse seen) (cons (cons key value) (cdr rest)))]
+      [else (loop (cdr rest) (cons (car rest) seen))])))
+
+;;@doc
+;; Returns a new association list without any of the entries for `key`, comparing keys
+;; with `equal?` like `assoc`. The original list is left unchanged. The key comes first,
+;; as in SRFI-1.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (alist-delete 'a (list (cons 'a 1) (cons 'b 2))) ;; => '((b . 2))
+;; (alist-delete 'c (list (cons 'a 1))) ;; => '((a . 1))
+;; ```
+(define (alist-delete key alist)
+  (filter (lambda (entry) (not (equal? (car entry) key))) alist))
+
+;;@doc
+;; Builds a list of length `n` where the element at index `i` is `(proc i)`,
+;; for indices `0` through `n - 1`.
+;;
+;; # Examples
+;;
+;; ```scheme
+;; (list-tabulate 4 (lambda (i) (* i i))) ;; => '(0 1 4 9)
+;; (list-tabulate 0 (lambda (i) i)) ;; => '()
+;; ```
+(define (list-tabulate n proc)
+  (when (or (not (integer? n)) (< n 0))
+    (error! "list-tabulate expects a non negative integer, found:" n))
+  (let loop ([i (- n 1)] [accum '()])
+    (if (< i 0)
+        accum
+        (loop (- i 1) (cons (proc i) accum)))))
+
+;;@doc
+;; Alias for `list-tabulate`.
+(define build-list list-tabulate)
+
 ;;; Macros go here:
 
 (define-syntax reset
@@ -658,6 +1139,32 @@
                                          (lambda ()
                                            expr ...)))]))
 
+;; Evaluates `body`, and if an error is raised, binds it to `var` and evaluates the first
+;; clause whose test is true, as in `cond`. If no clause matches, the error is raised again.
+(define-syntax guard
+  (syntax-rules (else)
+    [(guard (var clause ...) body ...)
+     (call-with-exception-handler (lambda (var) (guard "CLAUSES" var clause ...))
+                                  (lambda ()
+                                    body ...))]
+    [(guard "CLAUSES" var) (raise-error var)]
+    [(guard "CLAUSES" var [else e ...]) (begin e ...)]
+    [(guard "CLAUSES" var [test] rest ...)
+     (let ([result test]) (if result result (guard "CLAUSES" var rest ...)))]
+    [(guard "CLAUSES" var [test e ...] rest ...)
+     (if test (begin e ...) (guard "CLAUSES" var rest ...))]))
+
+;; Passes the asserted expression along, so a failure reports what was being checked
+(define-syntax assert!
+  (syntax-rules ()
+    [(assert! expr) (#%assert! expr (quote expr))]))
+
+;; Like `assert!`, but a failure is recorded instead of raising an error, so the rest of the
+;; program keeps running. The failures can be retrieved with `(get-failed-assertions)`.
+(define-syntax assert-continue!
+  (syntax-rules ()
+    [(assert-continue! expr) (#%assert-continue! expr (quote expr))]))
+
 (define-syntax case-lambda
   (syntax-rules ()
     [(case-lambda) (lambda args (error "CASE-LAMBDA without any clauses."))]
@@ -814,6 +1321,52 @@
     [(= (length result) 1) (car result)]
     [else result]))
 
+;; Spreads the elements of `lst` out as multiple values.
+;;
+;; (receive (a b) (list->values '(1 2)) (+ a b)) ;; => 3
+(define (list->values lst)
+  (apply values lst))
+
+;; Spreads the elements of `vec` out as multiple values. The optional `start` and `end`
+;; indices limit this to the elements in the range [start, end).
+;;
+;; (receive (a b) (vector->values (vector 1 2 3) 1) (+ a b)) ;; => 5
+(define (vector->values vec . range)
+  (define len (vector-length vec))
+  (define start (if (null? range) 0 (car range)))
+  (define end (if (or (null? range) (null? (cdr range))) len (cadr range)))
+  (when (not (and (exact-nonnegative-integer? start)
+                  (exact-nonnegative-integer? end)
+                  (<= start end)
+                  (<= end len)))
+    (error! "vector->values expects a range within the vector, found:" start end))
+  (let loop ([i (- end 1)] [acc '()])
+    (if (< i start)
+        (apply values acc)
+        (loop (- i 1) (cons (vector-ref vec i) acc)))))
+
+;; Calls `producer` and collects the values it returns into a list. A producer
+;; that returns a single non-list value yields a one element list.
+;;
+;; (values->list (lambda () (values 1 2 3))) ;; => '(1 2 3)
+(define (values->list producer)
+  (define result (producer))
+  (if (list? result) result (list result)))
+
+;; SRFI-8 `receive`: binds the values produced by `expr` to `formals` over the body.
+;; `formals` takes the same shape as the parameter list of a `lambda`, so a rest
+;; argument collects any remaining values.
+;;
+;; (receive (q r) (values 7 2) (+ q r)) ;; => 9
+(define-syntax receive
+  (syntax-rules ()
+    [(receive formals
+              expr
+              body ...)
+     (apply (lambda formals
+              body ...)
+            expr)]))
+
 (define-syntax @doc
   (syntax-rules (struct define/contract)
     ; [(_ documentation (define name body))
@@ -77,7 +77,7 @@ impl<'a> BaseTypeKind<'a> {
 
     fn from_str(input: &'a str) -> BaseTypeKind<'a> {
         match input {
-            "int?" | "integer?" => BaseTypeKind::Int,
+            "int?" => BaseTypeKind::Int,
             "string?" => BaseTypeKind::String,
             _ => BaseTypeKind::Other(input),
         }
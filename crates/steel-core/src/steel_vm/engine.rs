@@ -33,8 +33,9 @@ use crate::{
     },
     parser::{
         kernel::{fresh_kernel_image, Kernel},
-        parser::{ParseError, Parser, Sources},
+        parser::{lower_macro_and_require_definitions, ParseError, Parser, Sources},
     },
+    primitives::MetaOperations,
     rerrs::{back_trace, back_trace_to_string},
     rvals::{
         cycles::{install_printer, print_in_engine, PRINT_IN_ENGINE_DEFINITION},
@@ -51,7 +52,7 @@ use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
     rc::Rc,
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 use fxhash::{FxBuildHasher, FxHashMap};
@@ -68,6 +69,11 @@ use crate::parser::ast::IteratorExtensions;
 
 thread_local! {
     static KERNEL_BIN_FILE: Cell<Option<&'static [u8]>> = Cell::new(None);
+
+    // Registering every builtin module and requiring them all is the bulk of the work in
+    // creating a base engine, so do it once per thread and hand out clones, the same
+    // way `Engine::new` starts from the kernel image.
+    static BASE_IMAGE: Engine = Engine::new_base_image();
 }
 
 // Install the binary file to be used during bootup
@@ -145,6 +151,33 @@ pub struct EngineStatistics {
     pub sources_size: usize,
 }
 
+/// The outcome of running a script with [`Engine::run_tests`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestReport {
+    pub passed: usize,
+    pub failed: usize,
+    /// The asserted forms that did not hold, in the order they were checked
+    pub failures: Vec<String>,
+}
+
+impl TestReport {
+    pub fn total(&self) -> usize {
+        self.passed + self.failed
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+// Routes `assert!` through a recorder instead of the aborting `#%assert!`. Scripts that
+// build their own helpers (such as `assert-equal!`) on top of `assert!` are covered too.
+const TEST_ASSERT_MACRO: &str = r#"
+(define-syntax assert!
+  (syntax-rules ()
+    [(assert! expr) (#%test-assert! expr (quote expr))]))
+"#;
+
 #[derive(Debug, Clone, Copy)]
 pub struct GlobalCheckpoint {
     symbol_map_offset: usize,
@@ -923,6 +956,16 @@ impl Engine {
     /// ```
     #[inline]
     pub fn new_base() -> Self {
+        let mut vm = BASE_IMAGE.with(|x| x.clone());
+
+        // The kernel holds on to the defmacro transformers, give each engine its own
+        // so that macros don't leak between them.
+        vm.compiler.kernel = Some(Kernel::new());
+
+        vm
+    }
+
+    fn new_base_image() -> Self {
         let mut vm = Engine::new_raw();
         // Embed any primitives that we want to use
 
@@ -942,6 +985,89 @@ impl Engine {
         self
     }
 
+    /// Limit the number of function calls and backward jumps the engine may make, for running
+    /// untrusted code with a bounded amount of computation. Once the budget runs out, evaluation
+    /// stops with an [`ErrorKind::ResourceExhausted`](crate::rerrs::ErrorKind) error.
+    /// The budget is shared across subsequent runs; passing `None` removes the limit.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) -> &mut Self {
+        self.virtual_machine.set_fuel(fuel);
+        self
+    }
+
+    /// The amount of fuel left, if a limit has been set with [`Engine::set_fuel`]
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.virtual_machine.remaining_fuel()
+    }
+
+    /// Restart the numbering used by `gensym`, so that test suites can get the same
+    /// generated symbol names on every run. The counter is shared by every engine on the
+    /// current thread, so this resets it for all of them. Symbols generated before the reset
+    /// will be generated again afterwards, so resetting in the middle of a program risks
+    /// collisions.
+    pub fn reset_gensym_counter(&mut self) -> &mut Self {
+        crate::primitives::reset_gensym_counter();
+        self
+    }
+
+    /// Run `program` as a test script. Rather than stopping at the first failing `assert!`,
+    /// every assertion is recorded and the tally is returned as a [`TestReport`]. Errors
+    /// other than failed assertions still abort the run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate steel;
+    /// # use steel::steel_vm::engine::Engine;
+    /// let mut vm = Engine::new();
+    /// let report = vm
+    ///     .run_tests("(assert! (= 1 1)) (assert! (= 1 2)) (assert! (= 2 2))")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(report.passed, 2);
+    /// assert_eq!(report.failures, vec!["(= 1 2)".to_string()]);
+    /// ```
+    pub fn run_tests(&mut self, program: &str) -> Result<TestReport> {
+        let report = Arc::new(Mutex::new(TestReport::default()));
+        let recorder = Arc::clone(&report);
+
+        let record = move |args: &[SteelVal]| -> Result<SteelVal> {
+            let mut report = recorder.lock().unwrap();
+
+            match args {
+                [SteelVal::BoolV(true)] | [SteelVal::BoolV(true), _] => report.passed += 1,
+                [failed] | [_, failed] => {
+                    report.failed += 1;
+                    report.failures.push(failed.to_string());
+                }
+                _ => stop!(ArityMismatch => "assert! takes one argument"),
+            }
+
+            Ok(SteelVal::Void)
+        };
+
+        self.register_value(
+            "#%test-assert!",
+            SteelVal::BoxedFunction(Rc::new(BoxedDynFunction::new(
+                Arc::new(record),
+                Some("assert!"),
+                None,
+            ))),
+        );
+
+        self.compile_and_run_raw_program(TEST_ASSERT_MACRO)?;
+
+        let result = self.compile_and_run_raw_program(program.to_string());
+
+        // The `assert!` override stays defined in the engine, so go back to aborting on failure
+        self.register_value("#%test-assert!", MetaOperations::assert_truthy());
+
+        result?;
+
+        let report = report.lock().unwrap().clone();
+
+        Ok(report)
+    }
+
     #[inline]
     pub fn new_sandboxed() -> Self {
         let mut vm = Engine::new_raw();
@@ -1200,6 +1326,30 @@ impl Engine {
         self.compiler.add_search_directory(dir)
     }
 
+    /// Returns the directory that relative `require`s are resolved against for programs
+    /// that aren't associated with a file, if one has been set with
+    /// [`Engine::set_current_directory`].
+    pub fn current_directory(&self) -> Option<&PathBuf> {
+        self.virtual_machine.current_directory.as_ref()
+    }
+
+    /// Sets the directory that relative `require`s are resolved against for programs
+    /// that aren't associated with a file. This only affects this engine, the working
+    /// directory of the process is left alone. Passing `None` goes back to resolving
+    /// against the process working directory. Scripts can do the same with
+    /// `(current-directory path)`.
+    ///
+    /// ```
+    /// # extern crate steel;
+    /// # use steel::steel_vm::engine::Engine;
+    /// let mut vm = Engine::new();
+    /// vm.set_current_directory(Some(std::env::temp_dir()));
+    /// assert_eq!(vm.current_directory(), Some(&std::env::temp_dir()));
+    /// ```
+    pub fn set_current_directory(&mut self, dir: Option<PathBuf>) {
+        self.virtual_machine.current_directory = dir;
+    }
+
     pub(crate) fn new_printer() -> Self {
         let mut engine = fresh_kernel_image();
 
@@ -1299,6 +1449,7 @@ impl Engine {
         expr: E,
     ) -> Result<RawProgramWithSymbols> {
         let constants = self.constants();
+        self.sync_current_directory();
         self.compiler.compile_executable(
             expr,
             None,
@@ -1314,6 +1465,7 @@ impl Engine {
         path: PathBuf,
     ) -> Result<RawProgramWithSymbols> {
         let constants = self.constants();
+        self.sync_current_directory();
         self.compiler.compile_executable(
             expr,
             Some(path),
@@ -1461,6 +1613,7 @@ impl Engine {
         path: PathBuf,
     ) -> Result<Vec<SteelVal>> {
         let constants = self.constants();
+        self.sync_current_directory();
         let program = self.compiler.compile_executable(
             exprs,
             Some(path),
@@ -1479,6 +1632,7 @@ impl Engine {
         exprs: Vec<ExprKind>,
     ) -> Result<Vec<SteelVal>> {
         let constants = self.constants();
+        self.sync_current_directory();
         let program = self.compiler.compile_executable_from_expressions(
             exprs,
             self.modules.clone(),
@@ -1493,6 +1647,7 @@ impl Engine {
         exprs: E,
     ) -> Result<Vec<SteelVal>> {
         let constants = self.constants();
+        self.sync_current_directory();
         let program = self.compiler.compile_executable(
             exprs,
             None,
@@ -1504,6 +1659,37 @@ impl Engine {
         self.run_raw_program(program)
     }
 
+    /// Compiles and runs each top level form in `exprs` separately, so that an error in one
+    /// form doesn't prevent the rest from running. Definitions from the forms that succeed are
+    /// kept, which is what a REPL wants when a single line holds several forms.
+    ///
+    /// Returns the value of each form, or the error that it raised. Reading stops at the first
+    /// parse error, since there is no reliable place to resume from.
+    pub fn compile_and_run_each_form(&mut self, exprs: &str) -> Vec<Result<SteelVal>> {
+        let id = self.sources.add_source(exprs.to_string(), None);
+
+        let mut results = Vec::new();
+
+        for parsed in Parser::new(exprs, Some(id)).without_lowering() {
+            let expr = match parsed {
+                Ok(expr) => expr,
+                Err(e) => {
+                    results.push(Err(e.into()));
+                    break;
+                }
+            };
+
+            let result = lower_macro_and_require_definitions(expr)
+                .map_err(SteelErr::from)
+                .and_then(|expr| self.run_raw_program_from_exprs(vec![expr]))
+                .map(|mut values| values.pop().unwrap_or(SteelVal::Void));
+
+            results.push(result);
+        }
+
+        results
+    }
+
     pub fn raw_program_to_executable(
         &mut self,
         program: RawProgramWithSymbols,
@@ -1560,6 +1746,7 @@ impl Engine {
         path: Option<PathBuf>,
     ) -> Result<Vec<ExprKind>> {
         let constants = self.constants();
+        self.sync_current_directory();
         self.compiler.emit_expanded_ast(
             expr,
             constants,
@@ -1575,6 +1762,7 @@ impl Engine {
         path: Option<PathBuf>,
     ) -> Result<Vec<ExprKind>> {
         let constants = self.constants();
+        self.sync_current_directory();
         self.compiler.emit_expanded_ast_without_optimizations(
             expr,
             constants,
@@ -1599,6 +1787,7 @@ impl Engine {
         path: Option<PathBuf>,
     ) -> Result<String> {
         let constants = self.constants();
+        self.sync_current_directory();
         Ok(self
             .compiler
             .emit_expanded_ast(
@@ -1620,6 +1809,7 @@ impl Engine {
         path: Option<PathBuf>,
     ) -> Result<Vec<ExprKind>> {
         let constants = self.constants();
+        self.sync_current_directory();
         self.compiler.emit_expanded_ast(
             expr,
             constants,
@@ -1931,6 +2121,13 @@ impl Engine {
     //     self.run_with_path(exprs.as_str(), path_buf)
     // }
 
+    // The current directory lives on the VM so that `current-directory` can change it at
+    // run time, but it's the compiler that resolves relative requires against it
+    fn sync_current_directory(&mut self) {
+        self.compiler
+            .set_current_directory(self.virtual_machine.current_directory.clone());
+    }
+
     // TODO this does not take into account the issues with
     // people registering new functions that shadow the original one
     fn constants(&mut self) -> ImmutableHashMap<InternedString, SteelVal, FxBuildHasher> {
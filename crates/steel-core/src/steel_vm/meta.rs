@@ -13,14 +13,18 @@ use crate::values::port::SteelPortRepr;
 use crate::values::structs::SteelResult;
 use crate::{
     parser::ast::ExprKind,
+    rerrs::ErrorKind,
     rvals::Custom,
     values::port::{SteelPort, CAPTURED_OUTPUT_PORT, DEFAULT_OUTPUT_PORT},
     SteelErr, SteelVal,
 };
 use crate::{parser::expander::LocalMacroManager, rvals::Result};
-use crate::{parser::parser::ParseError, steel_vm::engine::Engine};
+use crate::{
+    parser::parser::{lower_macro_and_require_definitions, ParseError},
+    steel_vm::{engine::Engine, vm::VmCore},
+};
 
-use crate::stop;
+use crate::{builtin_stop, stop};
 
 #[derive(Clone)]
 pub(crate) struct EngineWrapper(Engine);
@@ -295,3 +299,45 @@ pub fn eval(program: String) -> List<SteelVal> {
 
     // Ok(values?.into_iter().flatten().collect::<List<_>>().into())
 }
+
+/// Eval a quoted expression, such as one built at runtime with `list`, returning the value
+/// of the expression.
+///
+/// The expression goes through the same expansion and lowering as source code does,
+/// so a list headed by `if`, `let`, `lambda` and friends is treated as that special form,
+/// exactly as if it had been written out literally.
+///
+/// The expression is evaluated in a sandboxed environment that belongs to the calling
+/// engine, not in the calling program's environment. It sees the builtins, and anything an
+/// earlier `eval` in the same engine defined, but none of the caller's own bindings, and
+/// nothing defined by `eval` in any other engine.
+pub(crate) fn eval_datum(ctx: &mut VmCore, args: &[SteelVal]) -> Option<Result<SteelVal>> {
+    if args.len() != 1 {
+        builtin_stop!(ArityMismatch => "eval expects one argument, found {}", args.len())
+    }
+
+    // Building an engine is expensive, so it's only done on the first `eval`. It's taken out
+    // while the expression runs, so an `eval` nested inside gets an engine of its own.
+    let mut engine = ctx
+        .thread
+        .eval_engine
+        .take()
+        .unwrap_or_else(|| Box::new(Engine::new_sandboxed()));
+
+    let result = eval_datum_in(&mut engine, &args[0]);
+
+    ctx.thread.eval_engine = Some(engine);
+
+    Some(result)
+}
+
+fn eval_datum_in(engine: &mut Engine, expr: &SteelVal) -> Result<SteelVal> {
+    let expr = ExprKind::try_from(expr)
+        .map_err(|e| SteelErr::new(ErrorKind::BadSyntax, format!("eval: {e}")))?;
+
+    let expr = lower_macro_and_require_definitions(expr).map_err(SteelErr::from)?;
+
+    engine
+        .run_raw_program_from_exprs(vec![expr])
+        .map(|mut values| values.pop().unwrap_or(SteelVal::Void))
+}
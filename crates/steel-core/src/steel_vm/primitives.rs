@@ -3,7 +3,9 @@ use super::{
     cache::WeakMemoizationTable,
     engine::Engine,
     register_fn::RegisterFn,
-    vm::{get_test_mode, list_modules, set_test_mode, VmCore},
+    vm::{
+        assert_continue, get_failed_assertions, get_test_mode, list_modules, set_test_mode, VmCore,
+    },
 };
 use crate::{
     gc::Gc,
@@ -18,8 +20,11 @@ use crate::{
         hashsets::hashset_module,
         lists::{list_module, UnRecoverableResult},
         nums::{
-            quotient, ADD_PRIMITIVE_DEFINITION, DIVIDE_PRIMITIVE_DEFINITION, INEXACTP_DEFINITION,
-            MULTIPLY_PRIMITIVE_DEFINITION, SUBTRACT_PRIMITIVE_DEFINITION,
+            big_rational_to_f64, bignum_to_f64, f64_to_exact, ADD_PRIMITIVE_DEFINITION,
+            DIVIDE_PRIMITIVE_DEFINITION, EXACT_INTEGER_SQRT_DEFINITION,
+            EXACT_NONNEGATIVE_INTEGERP_DEFINITION, INEXACTP_DEFINITION, MODULO_DEFINITION,
+            MULTIPLY_PRIMITIVE_DEFINITION, QUOTIENT_DEFINITION, REMAINDER_DEFINITION,
+            SUBTRACT_PRIMITIVE_DEFINITION,
         },
         port_module,
         process::process_module,
@@ -34,8 +39,8 @@ use crate::{
     rvals::{
         as_underlying_type,
         cycles::{BreadthFirstSearchSteelValVisitor, SteelCycleCollector},
-        FromSteelVal, FunctionSignature, MutFunctionSignature, SteelComplex, SteelString,
-        ITERATOR_FINISHED, NUMBER_EQUALITY_DEFINITION,
+        FromSteelVal, FunctionSignature, MutFunctionSignature, RestArgsIter, SteelComplex,
+        SteelString, ITERATOR_FINISHED, NUMBER_EQUALITY_DEFINITION,
     },
     steel_vm::{
         builtin::{get_function_metadata, get_function_name, Arity},
@@ -654,6 +659,12 @@ fn vector_module() -> BuiltInModule {
         .register_value("mut-vec-len", VectorOperations::mut_vec_length())
         .register_value("vector-length", VectorOperations::vec_length())
         .register_value("vector-append!", VectorOperations::mut_vec_append())
+        .register_value("vector-append", VectorOperations::vector_append())
+        .register_value("vector-copy", VectorOperations::vec_copy())
+        .register_value("vector-copy!", VectorOperations::mut_vec_copy())
+        .register_value("vector-resize", VectorOperations::vector_resize())
+        .register_value("string->vector", VectorOperations::string_to_vector())
+        .register_value("vector->string", VectorOperations::vector_to_string())
         .register_value("mut-vector-ref", VectorOperations::mut_vec_get())
         .register_value("vector-set!", VectorOperations::mut_vec_set())
         // Immutable vector operations
@@ -674,6 +685,12 @@ fn not(value: &SteelVal) -> bool {
     matches!(value, SteelVal::BoolV(false))
 }
 
+/// Returns #t if the value is a number of any kind.
+///
+/// The numeric predicates form a tower: every value that satisfies `integer?`
+/// also satisfies `rational?`, `real?`, `complex?` and `number?`, in that order.
+///
+/// (number? value) -> bool?
 #[steel_derive::function(name = "number?", constant = true)]
 pub fn numberp(value: &SteelVal) -> bool {
     matches!(
@@ -687,6 +704,10 @@ pub fn numberp(value: &SteelVal) -> bool {
     )
 }
 
+/// Returns #t if the value is a complex number. Every number is a complex number,
+/// so this is the same as `number?`.
+///
+/// (complex? value) -> bool?
 #[steel_derive::function(name = "complex?", constant = true)]
 pub fn complexp(value: &SteelVal) -> bool {
     numberp(value)
@@ -697,9 +718,25 @@ fn intp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::IntV(_) | SteelVal::BigNum(_))
 }
 
+/// Returns #t if the value is an integer. Unlike `int?`, this includes floating point
+/// numbers that have no fractional part.
+///
+/// (integer? value) -> bool?
+///
+/// Examples:
+/// ```scheme
+///   (integer? 3)   ⇒ #t
+///   (integer? 3.0) ⇒ #t
+///   (integer? 3.5) ⇒ #f
+///   (integer? 1/2) ⇒ #f
+/// ```
 #[steel_derive::function(name = "integer?", constant = true)]
 fn integerp(value: &SteelVal) -> bool {
-    intp(value)
+    match value {
+        SteelVal::IntV(_) | SteelVal::BigNum(_) => true,
+        SteelVal::NumV(n) => n.is_finite() && n.fract() == 0.0,
+        _ => false,
+    }
 }
 
 #[steel_derive::function(name = "float?", constant = true)]
@@ -707,6 +744,9 @@ fn floatp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::NumV(_))
 }
 
+/// Returns #t if the value is a real number, which is any number without an imaginary part.
+///
+/// (real? value) -> bool?
 #[steel_derive::function(name = "real?", constant = true)]
 pub fn realp(value: &SteelVal) -> bool {
     matches!(
@@ -749,6 +789,21 @@ fn stringp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::StringV(_))
 }
 
+/// Checks if the given value is a proper list.
+///
+/// Lists and pairs in steel are immutable, so there is no `set-cdr!` and a cyclic list can't
+/// be constructed. This means `list?` (and `length`) can check the value directly instead of
+/// walking the spine with cycle detection, and always terminate.
+///
+/// (list? any/c) -> bool?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (list? '(1 2 3)) ;; => #true
+/// > (list? '()) ;; => #true
+/// > (list? (cons 1 2)) ;; => #false
+/// ```
 #[steel_derive::function(name = "list?", constant = true)]
 fn listp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::ListV(_))
@@ -769,6 +824,21 @@ fn hashp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::HashMapV(_))
 }
 
+/// Checks if the given value is a hash table. This is the same check as `hash?`.
+///
+/// (hash-table? any/c) -> bool?
+///
+/// # Examples
+///
+/// ```scheme
+/// > (hash-table? (hash 'a 10)) ;; => #true
+/// > (hash-table? (hashset 10)) ;; => #false
+/// ```
+#[steel_derive::function(name = "hash-table?", constant = true)]
+fn hash_tablep(value: &SteelVal) -> bool {
+    matches!(value, SteelVal::HashMapV(_))
+}
+
 #[steel_derive::function(name = "set?", constant = true)]
 fn hashsetp(value: &SteelVal) -> bool {
     matches!(value, SteelVal::HashSetV(_))
@@ -855,6 +925,7 @@ fn identity_module() -> BuiltInModule {
         .register_native_fn_definition(VECTORP_DEFINITION)
         .register_native_fn_definition(SYMBOLP_DEFINITION)
         .register_native_fn_definition(HASHP_DEFINITION)
+        .register_native_fn_definition(HASH_TABLEP_DEFINITION)
         .register_native_fn_definition(HASHSETP_DEFINITION)
         .register_native_fn_definition(CONTINUATIONP_DEFINITION)
         .register_native_fn_definition(BOOLEANP_DEFINITION)
@@ -905,9 +976,9 @@ fn exact_to_inexact(number: &SteelVal) -> Result<SteelVal> {
     match number {
         SteelVal::IntV(i) => (*i as f64).into_steelval(),
         SteelVal::Rational(f) => f.to_f64().unwrap().into_steelval(),
-        SteelVal::BigRational(f) => f.to_f64().unwrap().into_steelval(),
+        SteelVal::BigRational(f) => big_rational_to_f64(f).into_steelval(),
         SteelVal::NumV(n) => n.into_steelval(),
-        SteelVal::BigNum(n) => Ok(SteelVal::NumV(n.to_f64().unwrap())),
+        SteelVal::BigNum(n) => Ok(SteelVal::NumV(bignum_to_f64(n))),
         SteelVal::Complex(x) => {
             SteelComplex::new(exact_to_inexact(&x.re)?, exact_to_inexact(&x.im)?).into_steelval()
         }
@@ -915,6 +986,28 @@ fn exact_to_inexact(number: &SteelVal) -> Result<SteelVal> {
     }
 }
 
+/// Converts an inexact number into the exact number it represents. Floats that aren't
+/// integers become the rational with exactly the same value.
+///
+/// (inexact->exact 0.5) ;; => 1/2
+#[steel_derive::function(name = "inexact->exact", constant = true)]
+fn inexact_to_exact(number: &SteelVal) -> Result<SteelVal> {
+    match number {
+        SteelVal::NumV(n) => match f64_to_exact(*n) {
+            Some(exact) => exact.into_steelval(),
+            None => stop!(ConversionError => "inexact->exact: no exact representation for {}", n),
+        },
+        SteelVal::Complex(x) => {
+            SteelComplex::new(inexact_to_exact(&x.re)?, inexact_to_exact(&x.im)?).into_steelval()
+        }
+        SteelVal::IntV(_)
+        | SteelVal::Rational(_)
+        | SteelVal::BigNum(_)
+        | SteelVal::BigRational(_) => Ok(number.clone()),
+        _ => stop!(TypeMismatch => "inexact->exact expects a number type, found: {}", number),
+    }
+}
+
 // Docs from racket:
 // (round x) → (or/c integer? +inf.0 -inf.0 +nan.0)
 //   x : real?
@@ -931,6 +1024,57 @@ fn round(number: &SteelVal) -> Result<SteelVal> {
     }
 }
 
+/// Returns the largest integer that is not greater than `number`.
+/// The result is exact for exact input and inexact for inexact input.
+///
+/// (floor (/ 5 2)) ;; => 2
+/// (floor -2.5) ;; => -3.0
+#[steel_derive::function(name = "floor", constant = true)]
+fn floor(number: &SteelVal) -> Result<SteelVal> {
+    match number {
+        SteelVal::IntV(i) => i.into_steelval(),
+        SteelVal::NumV(n) => n.floor().into_steelval(),
+        SteelVal::Rational(f) => f.floor().into_steelval(),
+        SteelVal::BigRational(f) => f.floor().into_steelval(),
+        SteelVal::BigNum(n) => Ok(SteelVal::BigNum(n.clone())),
+        _ => stop!(TypeMismatch => "floor expects a real number, found: {}", number),
+    }
+}
+
+/// Returns the smallest integer that is not less than `number`.
+/// The result is exact for exact input and inexact for inexact input.
+///
+/// (ceiling (/ 5 2)) ;; => 3
+/// (ceiling -2.5) ;; => -2.0
+#[steel_derive::function(name = "ceiling", constant = true)]
+fn ceiling(number: &SteelVal) -> Result<SteelVal> {
+    match number {
+        SteelVal::IntV(i) => i.into_steelval(),
+        SteelVal::NumV(n) => n.ceil().into_steelval(),
+        SteelVal::Rational(f) => f.ceil().into_steelval(),
+        SteelVal::BigRational(f) => f.ceil().into_steelval(),
+        SteelVal::BigNum(n) => Ok(SteelVal::BigNum(n.clone())),
+        _ => stop!(TypeMismatch => "ceiling expects a real number, found: {}", number),
+    }
+}
+
+/// Returns the integer closest to `number` whose absolute value is not greater than it.
+/// The result is exact for exact input and inexact for inexact input.
+///
+/// (truncate (/ -5 2)) ;; => -2
+/// (truncate 2.7) ;; => 2.0
+#[steel_derive::function(name = "truncate", constant = true)]
+fn truncate(number: &SteelVal) -> Result<SteelVal> {
+    match number {
+        SteelVal::IntV(i) => i.into_steelval(),
+        SteelVal::NumV(n) => n.trunc().into_steelval(),
+        SteelVal::Rational(f) => f.trunc().into_steelval(),
+        SteelVal::BigRational(f) => f.trunc().into_steelval(),
+        SteelVal::BigNum(n) => Ok(SteelVal::BigNum(n.clone())),
+        _ => stop!(TypeMismatch => "truncate expects a real number, found: {}", number),
+    }
+}
+
 /// Returns the absolute value of the given input
 #[steel_derive::function(name = "abs", constant = true)]
 fn abs(number: &SteelVal) -> Result<SteelVal> {
@@ -1067,14 +1211,22 @@ fn number_module() -> BuiltInModule {
         .register_native_fn_definition(SUBTRACT_PRIMITIVE_DEFINITION)
         .register_value("even?", NumOperations::even())
         .register_value("odd?", NumOperations::odd())
-        .register_fn("quotient", quotient)
+        .register_native_fn_definition(QUOTIENT_DEFINITION)
+        .register_native_fn_definition(REMAINDER_DEFINITION)
+        .register_native_fn_definition(MODULO_DEFINITION)
+        .register_native_fn_definition(EXACT_INTEGER_SQRT_DEFINITION)
         .register_value("arithmetic-shift", NumOperations::arithmetic_shift())
         .register_native_fn_definition(ABS_DEFINITION)
         .register_native_fn_definition(EXPT_DEFINITION)
         .register_native_fn_definition(ROUND_DEFINITION)
+        .register_native_fn_definition(FLOOR_DEFINITION)
+        .register_native_fn_definition(CEILING_DEFINITION)
+        .register_native_fn_definition(TRUNCATE_DEFINITION)
         .register_native_fn_definition(EXACT_TO_INEXACT_DEFINITION)
+        .register_native_fn_definition(INEXACT_TO_EXACT_DEFINITION)
         .register_native_fn_definition(EXACTP_DEFINITION)
         .register_native_fn_definition(INEXACTP_DEFINITION)
+        .register_native_fn_definition(EXACT_NONNEGATIVE_INTEGERP_DEFINITION)
         .register_native_fn_definition(EXP_DEFINITION)
         .register_native_fn_definition(LOG_DEFINITION);
 
@@ -1118,7 +1270,10 @@ fn equality_module() -> BuiltInModule {
             "equal?",
             SteelVal::FuncV(ensure_tonicity_two!(|a, b| a == b)),
         )
-        .register_value("eqv?", SteelVal::FuncV(ensure_tonicity_two!(|a, b| a == b)))
+        .register_value(
+            "eqv?",
+            SteelVal::FuncV(ensure_tonicity_two!(|a: &SteelVal, b: &SteelVal| a.eqv(b))),
+        )
         .register_value(
             "eq?",
             SteelVal::FuncV(ensure_tonicity_two!(
@@ -1181,7 +1336,10 @@ fn symbol_module() -> BuiltInModule {
     let mut module = BuiltInModule::new("steel/symbols");
     module
         .register_value("concat-symbols", SymbolOperations::concat_symbols())
-        .register_value("symbol->string", SymbolOperations::symbol_to_string());
+        .register_value("symbol-append", SymbolOperations::concat_symbols())
+        .register_value("symbol->string", SymbolOperations::symbol_to_string())
+        .register_value("gensym", SymbolOperations::gensym())
+        .register_value("gensym-reset!", SymbolOperations::gensym_reset());
     module
 }
 
@@ -1251,6 +1409,7 @@ fn sandboxed_meta_module() -> BuiltInModule {
         // TODO: @Matt -> implement the traits for modules as well
         // .register_fn("Engine::new", super::meta::EngineWrapper::new)
         .register_fn("eval!", super::meta::eval)
+        .register_value("eval", SteelVal::BuiltIn(super::meta::eval_datum))
         .register_fn("value->iterator", crate::rvals::value_into_iterator)
         .register_value("iter-next!", SteelVal::FuncV(crate::rvals::iterator_next));
     // .register_fn("run!", super::meta::EngineWrapper::call)
@@ -1627,6 +1786,12 @@ fn meta_module() -> BuiltInModule {
             SteelCycleCollector::values,
         )
         .register_value("assert!", MetaOperations::assert_truthy())
+        .register_value("#%assert!", MetaOperations::assert_truthy())
+        .register_value("#%assert-continue!", SteelVal::BuiltIn(assert_continue))
+        .register_value(
+            "get-failed-assertions",
+            SteelVal::BuiltIn(get_failed_assertions),
+        )
         .register_value("active-object-count", MetaOperations::active_objects())
         .register_value("inspect-bytecode", MetaOperations::inspect_bytecode())
         .register_value("memory-address", MetaOperations::memory_address())
@@ -1648,6 +1813,9 @@ fn meta_module() -> BuiltInModule {
         .register_value("error-with-span", error_with_src_loc())
         .register_value("raise-error-with-span", error_from_error_with_span())
         .register_value("raise-error", raise_error_from_error())
+        .register_value("error-object?", error_object_huh())
+        .register_value("error-object-message", error_object_message())
+        .register_native_fn_definition(EXIT_DEFINITION)
         .register_value("call/cc", SteelVal::BuiltIn(super::vm::call_cc))
         .register_value(
             "call-with-exception-handler",
@@ -1663,6 +1831,7 @@ fn meta_module() -> BuiltInModule {
             SteelVal::BuiltIn(super::vm::call_cc),
         )
         .register_fn("eval!", super::meta::eval)
+        .register_value("eval", SteelVal::BuiltIn(super::meta::eval_datum))
         .register_fn("value->string", super::meta::value_to_string)
         // TODO: @Matt -> implement the traits for modules as well
         .register_fn("Engine::new", super::meta::EngineWrapper::new)
@@ -1828,6 +1997,59 @@ pub fn raise_error_from_error() -> SteelVal {
     })
 }
 
+pub fn error_object_huh() -> SteelVal {
+    SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+        if args.len() != 1 {
+            stop!(ArityMismatch => "error-object? takes one argument");
+        }
+
+        Ok(SteelVal::BoolV(SteelErr::from_steelval(&args[0]).is_ok()))
+    })
+}
+
+pub fn error_object_message() -> SteelVal {
+    SteelVal::FuncV(|args: &[SteelVal]| -> Result<SteelVal> {
+        if args.len() != 1 {
+            stop!(ArityMismatch => "error-object-message takes one argument");
+        }
+
+        let steel_error = SteelErr::from_steelval(&args[0])?;
+
+        // `error` joins its arguments with a leading space
+        Ok(SteelVal::StringV(steel_error.message().trim_start().into()))
+    })
+}
+
+/// Stops evaluation, handing `code` back to the host as the exit code. This doesn't exit
+/// the process; the evaluation returns an error whose exit code can be read with
+/// `SteelErr::exit_code`. Exception handlers don't run on the way out.
+///
+/// `#true` and no argument mean success (0), and `#false` means failure (1).
+///
+/// (exit [code]) -> !
+///
+/// * code : (or/c int? bool?)
+#[steel_derive::function(name = "exit")]
+fn exit(mut code: RestArgsIter<'_, &SteelVal>) -> Result<SteelVal> {
+    let status = match code.next().transpose()? {
+        None | Some(SteelVal::BoolV(true)) => 0,
+        Some(SteelVal::BoolV(false)) => 1,
+        Some(SteelVal::IntV(n)) => match i32::try_from(*n) {
+            Ok(n) => n,
+            Err(_) => stop!(ContractViolation => "exit code out of range: {}", n),
+        },
+        Some(other) => {
+            stop!(TypeMismatch => "exit expects an integer or a boolean, found: {}", other)
+        }
+    };
+
+    if let Some(next) = code.next() {
+        stop!(ArityMismatch => "exit expects at most one argument, found an additional argument: {}", next?);
+    }
+
+    Err(SteelErr::exit(status))
+}
+
 // Be able to introspect on the modules - probably just need to add a modules
 // field on the vm, or use a wrapped type with modules to find things
 // TODO: Add magic number for modules. - key to magic number, do pointer equality
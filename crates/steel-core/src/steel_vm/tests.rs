@@ -608,3 +608,631 @@ mod find_closest_match_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod error_span_tests {
+    use crate::parser::span::Span;
+    use crate::rerrs::ErrorKind;
+    use crate::rvals::Result;
+    use crate::steel_vm::engine::Engine;
+    use crate::stop;
+
+    fn formatted_error_with_span(value: usize) -> Result<()> {
+        stop!(Generic => "bad value: {}", value; Span::new(3, 7, None))
+    }
+
+    fn formatted_error_without_span(value: usize) -> Result<()> {
+        stop!(Generic => "bad value: {}", value)
+    }
+
+    #[test]
+    fn stop_with_format_arguments_attaches_span() {
+        let err = formatted_error_with_span(10).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Generic);
+        assert_eq!(err.span(), Some(Span::new(3, 7, None)));
+    }
+
+    #[test]
+    fn stop_without_span_leaves_span_empty() {
+        let err = formatted_error_without_span(10).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Generic);
+        assert_eq!(err.span(), None);
+    }
+
+    #[test]
+    fn primitive_error_carries_call_site_span() {
+        let program = "(define (first-of lst) (car lst))\n(first-of '())";
+        let call_site = program.find("(car").unwrap();
+
+        let mut vm = Engine::new();
+        let err = vm.compile_and_run_raw_program(program).unwrap_err();
+        let span = err
+            .span()
+            .expect("error raised by a primitive should have a span");
+
+        assert!(span.start >= call_site && span.end <= program.find('\n').unwrap());
+    }
+
+    #[test]
+    fn misplaced_else_in_cond_is_bad_syntax() {
+        let mut vm = Engine::new();
+        let err = vm
+            .compile_and_run_raw_program("(cond [else 1] [#t 2])")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::BadSyntax);
+    }
+}
+
+#[cfg(test)]
+mod fuel_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn infinite_loop_runs_out_of_fuel() {
+        let mut vm = Engine::new();
+        vm.set_fuel(Some(10_000));
+
+        let err = vm
+            .compile_and_run_raw_program("(define (spin) (spin)) (spin)")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ResourceExhausted);
+        assert_eq!(vm.remaining_fuel(), Some(0));
+    }
+
+    #[test]
+    fn running_out_of_fuel_skips_exception_handlers() {
+        let mut vm = Engine::new();
+        vm.set_fuel(Some(10_000));
+
+        let err = vm
+            .compile_and_run_raw_program(
+                "(define (spin) (spin)) (call-with-exception-handler (lambda (err) 'caught) spin)",
+            )
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ResourceExhausted);
+    }
+
+    #[test]
+    fn program_completes_with_fuel_to_spare() {
+        let mut vm = Engine::new();
+        vm.set_fuel(Some(1_000_000));
+
+        let result = vm
+            .compile_and_run_raw_program(
+                "(define (fib n) (if (<= n 2) 1 (+ (fib (- n 1)) (fib (- n 2))))) (fib 10)",
+            )
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::IntV(55)));
+        assert!(vm.remaining_fuel().unwrap() > 0);
+
+        vm.set_fuel(None);
+        assert_eq!(vm.remaining_fuel(), None);
+    }
+
+    #[test]
+    fn straight_line_code_uses_no_fuel() {
+        let mut vm = Engine::new();
+        vm.set_fuel(Some(0));
+
+        let result = vm
+            .compile_and_run_raw_program("(define x 10) (if x 'yes 'no)")
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::SymbolV("yes".into())));
+    }
+}
+
+#[cfg(test)]
+mod each_form_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn error_in_one_form_keeps_earlier_definitions() {
+        let mut vm = Engine::new();
+
+        let results = vm.compile_and_run_each_form("(define x 10) (car '()) (+ x 1)");
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap(), &SteelVal::IntV(11));
+
+        // The definition is still around for later input
+        let later = vm.compile_and_run_raw_program("x").unwrap();
+        assert_eq!(later, vec![SteelVal::IntV(10)]);
+    }
+
+    #[test]
+    fn parse_error_stops_reading() {
+        let mut vm = Engine::new();
+
+        let results = vm.compile_and_run_each_form("(+ 1 2) (+ 1");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &SteelVal::IntV(3));
+        assert_eq!(results[1].as_ref().unwrap_err().kind(), ErrorKind::Parse);
+    }
+}
+
+#[cfg(test)]
+mod shared_image_tests {
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn base_engines_resolve_builtins() {
+        for _ in 0..3 {
+            let mut vm = Engine::new_base();
+            let result = vm.compile_and_run_raw_program("(+ 1 2 3)").unwrap();
+            assert_eq!(result, vec![SteelVal::IntV(6)]);
+        }
+    }
+
+    #[test]
+    fn definitions_dont_leak_between_base_engines() {
+        let mut first = Engine::new_base();
+        let mut second = Engine::new_base();
+
+        first.compile_and_run_raw_program("(define x 10)").unwrap();
+
+        assert!(second.compile_and_run_raw_program("x").is_err());
+        assert!(Engine::new_base().compile_and_run_raw_program("x").is_err());
+
+        assert_eq!(
+            first.compile_and_run_raw_program("x").unwrap(),
+            vec![SteelVal::IntV(10)]
+        );
+    }
+
+    #[test]
+    fn definitions_dont_leak_between_engines() {
+        let mut first = Engine::new();
+        let mut second = Engine::new();
+
+        first
+            .compile_and_run_raw_program("(define (double x) (* 2 x))")
+            .unwrap();
+
+        assert!(second.compile_and_run_raw_program("(double 2)").is_err());
+        assert_eq!(
+            first.compile_and_run_raw_program("(double 2)").unwrap(),
+            vec![SteelVal::IntV(4)]
+        );
+    }
+}
+
+#[cfg(test)]
+mod current_directory_tests {
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn relative_requires_resolve_against_current_directory() {
+        let dir = std::env::temp_dir().join(format!("steel-current-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("sibling.scm"),
+            "(provide triple) (define (triple x) (* 3 x))",
+        )
+        .unwrap();
+
+        let mut vm = Engine::new();
+        assert!(vm.current_directory().is_none());
+
+        vm.set_current_directory(Some(dir.clone()));
+        assert_eq!(vm.current_directory(), Some(&dir));
+
+        let result = vm
+            .compile_and_run_raw_program(r#"(require "sibling.scm") (triple 5)"#)
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::IntV(15)));
+
+        // The process working directory is left alone
+        assert_ne!(std::env::current_dir().unwrap(), dir);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn current_directory_set_from_scheme_affects_later_requires() {
+        let dir = std::env::temp_dir().join(format!("steel-scheme-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("sibling.scm"),
+            "(provide double) (define (double x) (* 2 x))",
+        )
+        .unwrap();
+
+        let mut vm = Engine::new();
+        vm.register_value(
+            "target-dir",
+            SteelVal::StringV(dir.to_str().unwrap().into()),
+        );
+
+        vm.compile_and_run_raw_program("(current-directory target-dir)")
+            .unwrap();
+        assert_eq!(vm.current_directory(), Some(&dir));
+
+        let result = vm
+            .compile_and_run_raw_program(r#"(require "sibling.scm") (double 21)"#)
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::IntV(42)));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_relative_require_is_an_error() {
+        let dir = std::env::temp_dir().join(format!("steel-empty-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut vm = Engine::new();
+        vm.set_current_directory(Some(dir.clone()));
+
+        assert!(vm
+            .compile_and_run_raw_program(r#"(require "sibling.scm")"#)
+            .is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod exit_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn exit_stops_evaluation_with_the_code() {
+        let mut vm = Engine::new();
+        let err = vm
+            .compile_and_run_raw_program("(define x 1) (exit 3) (set! x 2)")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Exit);
+        assert_eq!(err.exit_code(), Some(3));
+        assert_eq!(vm.extract_value("x").unwrap(), SteelVal::IntV(1));
+    }
+
+    #[test]
+    fn exit_defaults_to_success() {
+        let mut vm = Engine::new();
+
+        let err = vm.compile_and_run_raw_program("(exit)").unwrap_err();
+        assert_eq!(err.exit_code(), Some(0));
+
+        let err = vm.compile_and_run_raw_program("(exit #false)").unwrap_err();
+        assert_eq!(err.exit_code(), Some(1));
+    }
+
+    #[test]
+    fn exit_skips_exception_handlers() {
+        let mut vm = Engine::new();
+        let err = vm
+            .compile_and_run_raw_program(
+                "(call-with-exception-handler (lambda (err) 'caught) (lambda () (exit 4)))",
+            )
+            .unwrap_err();
+
+        assert_eq!(err.exit_code(), Some(4));
+    }
+
+    #[test]
+    fn other_errors_have_no_exit_code() {
+        let mut vm = Engine::new();
+        let err = vm.compile_and_run_raw_program("(car '())").unwrap_err();
+
+        assert_eq!(err.exit_code(), None);
+    }
+}
+
+#[cfg(test)]
+mod gensym_tests {
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    const PROGRAM: &str = "(list (gensym) (gensym 'tmp) (gensym \"x\"))";
+
+    #[test]
+    fn reset_gensym_counter_repeats_names() {
+        let mut vm = Engine::new();
+
+        vm.reset_gensym_counter();
+        let first = vm.compile_and_run_raw_program(PROGRAM).unwrap();
+
+        vm.reset_gensym_counter();
+        let second = vm.compile_and_run_raw_program(PROGRAM).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            vec![SteelVal::ListV(
+                vec![
+                    SteelVal::SymbolV("##gensym1".into()),
+                    SteelVal::SymbolV("##tmp2".into()),
+                    SteelVal::SymbolV("##x3".into()),
+                ]
+                .into()
+            )]
+        );
+    }
+
+    #[test]
+    fn gensym_reset_from_scheme() {
+        let mut vm = Engine::new();
+
+        let result = vm
+            .compile_and_run_raw_program(
+                "(gensym-reset!) (define a (gensym)) (gensym-reset!) (equal? a (gensym))",
+            )
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::BoolV(true)));
+    }
+
+    #[test]
+    fn gensym_names_do_not_clash_with_source_identifiers() {
+        let mut vm = Engine::new();
+
+        let result = vm
+            .compile_and_run_raw_program("(gensym-reset!) (eq? (gensym) 'gensym1)")
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::BoolV(false)));
+    }
+
+    #[test]
+    fn gensym_generates_distinct_names() {
+        let mut vm = Engine::new();
+
+        let result = vm
+            .compile_and_run_raw_program("(equal? (gensym) (gensym))")
+            .unwrap();
+
+        assert_eq!(result, vec![SteelVal::BoolV(false)]);
+    }
+}
+
+#[cfg(test)]
+mod run_tests_tests {
+    use crate::steel_vm::engine::{Engine, TestReport};
+
+    #[test]
+    fn run_tests_collects_every_assertion() {
+        let mut vm = Engine::new();
+
+        let report = vm
+            .run_tests(
+                r#"
+                (define (square x) (* x x))
+                (assert! (= (square 3) 9))
+                (assert! (equal? (list 1 2) (list 1 2)))
+                (assert! (= (square 2) 5))
+                (assert! (string? 10))
+                (assert! (> 2 1))
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            report,
+            TestReport {
+                passed: 3,
+                failed: 2,
+                failures: vec!["(= (square 2) 5)".to_string(), "(string? 10)".to_string()],
+            }
+        );
+        assert_eq!(report.total(), 5);
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn run_tests_covers_helpers_built_on_assert() {
+        let mut vm = Engine::new();
+
+        let report = vm
+            .run_tests(
+                r#"
+                (define-syntax assert-equal!
+                  (syntax-rules ()
+                    [(_ expected actual) (assert! (equal? expected actual))]))
+
+                (assert-equal! 4 (+ 2 2))
+                (assert-equal! 5 (+ 2 2))
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(report.passed, 1);
+        assert_eq!(report.failures, vec!["(equal? 5 (+ 2 2))".to_string()]);
+    }
+
+    #[test]
+    fn run_tests_still_reports_other_errors() {
+        let mut vm = Engine::new();
+
+        assert!(vm.run_tests("(assert! #t) (car '())").is_err());
+    }
+
+    #[test]
+    fn assert_aborts_again_after_run_tests() {
+        let mut vm = Engine::new();
+
+        let report = vm.run_tests("(assert! #t)").unwrap();
+        assert!(report.is_success());
+
+        assert!(vm.compile_and_run_raw_program("(assert! #f)").is_err());
+    }
+}
+
+#[cfg(test)]
+mod quasiquote_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn unquote_outside_quasiquote_is_bad_syntax() {
+        let mut vm = Engine::new();
+
+        let err = vm
+            .compile_and_run_raw_program("(define x 10) (list 1 ,x)")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSyntax);
+
+        let err = vm
+            .compile_and_run_raw_program("(list 1 ,@(list 2 3))")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BadSyntax);
+    }
+}
+
+#[cfg(test)]
+mod letrec_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn letrec_mutual_recursion() {
+        let mut vm = Engine::new();
+
+        let result = vm
+            .compile_and_run_raw_program(
+                r#"
+                (letrec ([even? (lambda (n) (if (= n 0) #t (odd? (- n 1))))]
+                         [odd? (lambda (n) (if (= n 0) #f (even? (- n 1))))])
+                  (even? 10))
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(result.last(), Some(&SteelVal::BoolV(true)));
+    }
+
+    #[test]
+    fn letrec_reference_to_uninitialized_binding() {
+        let mut vm = Engine::new();
+
+        let err = vm
+            .compile_and_run_raw_program("(letrec ([a (+ b 1)] [b 10]) a)")
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::FreeIdentifier);
+        assert!(err
+            .to_string()
+            .contains("Cannot reference an identifier before its definition: b"));
+    }
+}
+
+#[cfg(test)]
+mod string_to_symbol_tests {
+    use crate::parser::interner::InternedString;
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn runtime_symbols_are_not_interned() {
+        let mut vm = Engine::new();
+
+        vm.compile_and_run_raw_program(
+            r#"
+            (define (make-symbols n)
+              (let loop ([i 0])
+                (when (< i n)
+                  (string->symbol (string-append "transient-symbol-" (number->string i)))
+                  (loop (+ i 1)))))
+
+            (make-symbols 10000)
+            "#,
+        )
+        .unwrap();
+
+        // None of the symbols made while running ended up in the intern table
+        assert!(InternedString::try_get("transient-symbol-0").is_none());
+        assert!(InternedString::try_get("transient-symbol-9999").is_none());
+    }
+
+    #[test]
+    fn runtime_symbols_are_eq_to_literal_symbols() {
+        let mut vm = Engine::new();
+
+        let result = vm
+            .compile_and_run_raw_program(
+                r#"
+                (list (eq? (string->symbol "foo") 'foo)
+                      (eq? (string->symbol "foo") (string->symbol "foo"))
+                      (eq? (string->symbol "foo") 'bar))
+                "#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            result.last(),
+            Some(&SteelVal::ListV(
+                vec![
+                    SteelVal::BoolV(true),
+                    SteelVal::BoolV(true),
+                    SteelVal::BoolV(false)
+                ]
+                .into()
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod variadic_lambda_tests {
+    use crate::rerrs::ErrorKind;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn too_few_required_arguments_is_an_arity_error() {
+        let mut vm = Engine::new();
+
+        vm.compile_and_run_raw_program("(define (f a b . rest) (list a b rest))")
+            .unwrap();
+
+        let err = vm.compile_and_run_raw_program("(f 1)").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::ArityMismatch);
+        assert!(err
+            .to_string()
+            .contains("expected at least 2 arguments, found 1"));
+    }
+}
+
+#[cfg(test)]
+mod eval_tests {
+    use crate::rvals::SteelVal;
+    use crate::steel_vm::engine::Engine;
+
+    #[test]
+    fn eval_definitions_are_not_shared_between_engines() {
+        let mut first = Engine::new();
+        let mut second = Engine::new();
+
+        first
+            .compile_and_run_raw_program("(eval '(define secret 1))")
+            .unwrap();
+
+        let result = first.compile_and_run_raw_program("(eval 'secret)").unwrap();
+        assert_eq!(result, vec![SteelVal::IntV(1)]);
+
+        assert!(second
+            .compile_and_run_raw_program("(eval 'secret)")
+            .is_err());
+    }
+}
@@ -36,16 +36,18 @@ use crate::{
 use crate::{
     env::Env,
     gc::Gc,
-    parser::span::Span,
+    parser::{interner::InternedString, span::Span},
     rerrs::{ErrorKind, SteelErr},
     rvals::{Result, SteelVal},
     stop,
     values::functions::ByteCodeLambda,
 };
+use std::path::PathBuf;
 use std::rc::Weak;
 use std::{cell::RefCell, collections::HashMap, iter::Iterator, rc::Rc};
 
 use super::builtin::DocTemplate;
+use super::engine::Engine;
 
 use crate::values::lists::List;
 
@@ -321,6 +323,16 @@ pub struct SteelThread {
     pub(crate) current_frame: StackFrame,
     pub(crate) stack_frames: Vec<StackFrame>,
     pub(crate) constant_map: ConstantMap,
+    // Remaining budget of calls and backward jumps. When this runs out, execution stops
+    // with a `ResourceExhausted` error. `None` means there is no limit.
+    pub(crate) fuel: Option<u64>,
+    // Messages for the `assert-continue!` checks that failed, in the order they happened
+    pub(crate) failed_assertions: Vec<String>,
+    // Directory that relative requires are resolved against, set with `current-directory`.
+    // `None` means the process working directory is used.
+    pub(crate) current_directory: Option<PathBuf>,
+    // Sandboxed engine that `eval` runs expressions in, created on the first `eval`
+    pub(crate) eval_engine: Option<Box<Engine>>,
 }
 
 #[derive(Clone)]
@@ -386,9 +398,23 @@ impl SteelThread {
             // we'll have each thread default to an empty constant map, and replace it with the map bundled
             // with the executables
             constant_map: DEFAULT_CONSTANT_MAP.with(|x| x.clone()),
+            fuel: None,
+            failed_assertions: Vec::new(),
+            current_directory: None,
+            eval_engine: None,
         }
     }
 
+    // Limit the number of calls and backward jumps that can be made, or lift the limit with `None`
+    pub fn set_fuel(&mut self, fuel: Option<u64>) -> &mut Self {
+        self.fuel = fuel;
+        self
+    }
+
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
     // If you want to explicitly turn off contracts, you can do so
     pub fn with_contracts(&mut self, contracts: bool) -> &mut Self {
         self.runtime_options.contracts_on = contracts;
@@ -576,78 +602,10 @@ impl SteelThread {
             // (let () (call-with-exception-handler (lambda (x) (displayln x)) (lambda () (+ 10 20 (error "oops!")))) (displayln "hi"))
 
             if let Err(e) = result {
-                while let Some(mut last) = vm_instance.thread.stack_frames.pop() {
-                    // Unwind the stack, close continuation marks here!
-                    // vm_instance.close_continuation_marks(&last);
-
-                    // For whatever reason - if we're at the top, we shouldn't go down below 0
-                    if vm_instance.pop_count == 0 {
-                        return Err(e);
-                    }
-
-                    // Drop the pop count along with everything else we're doing
-                    vm_instance.pop_count -= 1;
-
-                    if last.weak_continuation_mark.is_some() {
-                        vm_instance.thread.stack.truncate(last.sp);
-                        vm_instance.ip = last.ip;
-                        vm_instance.sp = vm_instance.get_last_stack_frame_sp();
-                        vm_instance.instructions = Rc::clone(&last.instructions);
-
-                        vm_instance.close_continuation_marks(&last);
-                    }
-
-                    if let Some(handler) = last.handler {
-                        // Drop the stack BACK to where it was on this level
-                        vm_instance.thread.stack.truncate(last.sp);
-
-                        vm_instance.thread.stack.push(e.into_steelval()?);
-
-                        // If we're at the top level, we need to handle this _slightly_ differently
-                        // if vm_instance.stack_frames.is_empty() {
-                        // Somehow update the main instruction group to _just_ be the new group
-                        match handler.as_ref() {
-                            SteelVal::Closure(closure) => {
-                                if vm_instance.thread.stack_frames.is_empty() {
-                                    vm_instance.sp = last.sp;
-
-                                    // Push on a dummy stack frame if we're at the top
-                                    vm_instance.thread.stack_frames.push(StackFrame::new(
-                                        last.sp,
-                                        Gc::clone(&closure),
-                                        0,
-                                        Rc::from([]),
-                                    ));
-                                }
-
-                                vm_instance.sp = last.sp;
-                                vm_instance.instructions = closure.body_exp();
-                                // vm_instance.spans = closure.spans();
-
-                                last.handler = None;
-
-                                #[cfg(not(feature = "unsafe-internals"))]
-                                {
-                                    last.function = closure.clone();
-                                }
-
-                                vm_instance.ip = 0;
-
-                                // Put this back as the last stack frame
-                                vm_instance.thread.stack_frames.push(last);
-
-                                vm_instance.pop_count += 1;
-                            }
-                            _ => {
-                                stop!(TypeMismatch => "expected a function for the exception handler, found: {}", handler)
-                            }
-                        }
-
-                        continue 'outer;
-                    }
+                match vm_instance.unwind_to_exception_handler(e, 0) {
+                    Ok(()) => continue 'outer,
+                    Err(e) => return Err(e),
                 }
-
-                return Err(e);
             } else {
                 // self.profiler.report();
                 // self.profiler.report_time_spend();
@@ -1269,6 +1227,109 @@ impl<'a> VmCore<'a> {
         }
     }
 
+    // Fuel is only charged where execution can repeat - at calls and backward jumps - so
+    // straight line code doesn't pay for it, while any loop still pays on every trip
+    fn consume_fuel(&mut self) -> Result<()> {
+        if let Some(fuel) = self.thread.fuel.as_mut() {
+            if *fuel == 0 {
+                stop!(ResourceExhausted => "evaluation ran out of fuel"; self.current_span());
+            }
+
+            *fuel -= 1;
+        }
+
+        Ok(())
+    }
+
+    // This is our pseudo "dynamic unwind" - walk back down the stack frames, stopping at
+    // `floor`, looking for an exception handler. If one is found, the VM is set up to run
+    // the handler and execution can resume. Otherwise the error is handed back.
+    fn unwind_to_exception_handler(&mut self, e: SteelErr, floor: usize) -> Result<()> {
+        // Running out of fuel can't be recovered from, since the handler would
+        // have no budget left to run with. Exiting skips the handlers by design.
+        if matches!(e.kind(), ErrorKind::ResourceExhausted | ErrorKind::Exit) {
+            return Err(e);
+        }
+
+        while self.thread.stack_frames.len() > floor {
+            let mut last = self.thread.stack_frames.pop().unwrap();
+
+            // Unwind the stack, close continuation marks here!
+            // self.close_continuation_marks(&last);
+
+            // For whatever reason - if we're at the top, we shouldn't go down below 0
+            if self.pop_count == 0 {
+                return Err(e);
+            }
+
+            // Drop the pop count along with everything else we're doing
+            self.pop_count -= 1;
+
+            if last.weak_continuation_mark.is_some() {
+                self.thread.stack.truncate(last.sp);
+                self.ip = last.ip;
+                self.sp = self.get_last_stack_frame_sp();
+                self.instructions = Rc::clone(&last.instructions);
+
+                self.close_continuation_marks(&last);
+            }
+
+            if let Some(handler) = last.handler {
+                // Drop the stack BACK to where it was on this level
+                self.thread.stack.truncate(last.sp);
+
+                self.thread.stack.push(e.into_steelval()?);
+
+                // If we're at the top level, we need to handle this _slightly_ differently
+                // if self.stack_frames.is_empty() {
+                // Somehow update the main instruction group to _just_ be the new group
+                match handler.as_ref() {
+                    SteelVal::Closure(closure) => {
+                        if self.thread.stack_frames.is_empty() {
+                            self.sp = last.sp;
+
+                            // Push on a dummy stack frame if we're at the top
+                            self.thread.stack_frames.push(StackFrame::new(
+                                last.sp,
+                                Gc::clone(&closure),
+                                0,
+                                Rc::from([]),
+                            ));
+                        }
+
+                        self.sp = last.sp;
+                        self.instructions = closure.body_exp();
+                        // self.spans = closure.spans();
+
+                        // The handler runs in place of the thunk, without its own handler
+                        // installed. Anything it raises goes to the next handler out, which is
+                        // the handler stack that was in place when it was installed.
+                        last.handler = None;
+
+                        #[cfg(not(feature = "unsafe-internals"))]
+                        {
+                            last.function = closure.clone();
+                        }
+
+                        self.ip = 0;
+
+                        // Put this back as the last stack frame
+                        self.thread.stack_frames.push(last);
+
+                        self.pop_count += 1;
+                    }
+                    _ => {
+                        stop!(TypeMismatch => "expected a function for the exception handler, found: {}", handler)
+                    }
+                }
+
+                return Ok(());
+            }
+        }
+
+        Err(e)
+    }
+
     pub fn snapshot_stack_trace(&self) -> DehydratedStackTrace {
         DehydratedStackTrace::new(
             self.thread
@@ -1381,13 +1442,28 @@ impl<'a> VmCore<'a> {
 
         // let old_stack_index = self.stack_index;
 
+        // The caller has already pushed the frame for the function being called
+        let frame_floor = self.thread.stack_frames.len().saturating_sub(1);
+
         self.ip = 0;
         // Force the execution to be done earlier
         self.pop_count = 1;
 
         self.depth += 1;
 
-        let res = self.vm();
+        // An error raised while we're nested inside of a native function (e.g. the function
+        // passed to `map`) still needs to find the handlers installed within this call,
+        // otherwise things like the after thunks of `dynamic-wind` would never run.
+        let res = loop {
+            match self.vm() {
+                Ok(value) => break Ok(value),
+                Err(e) => {
+                    if let Err(e) = self.unwind_to_exception_handler(e, frame_floor) {
+                        break Err(e);
+                    }
+                }
+            }
+        };
 
         self.depth -= 1;
 
@@ -2224,6 +2300,8 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
+
                     let read_local = &self.instructions[self.ip + 1];
                     let push_const = &self.instructions[self.ip + 2];
 
@@ -2249,6 +2327,7 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
                     self.ip += 1;
                     let next_inst = self.instructions[self.ip];
                     self.handle_call_global(
@@ -2261,6 +2340,7 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
                     let next_inst = self.instructions[self.ip + 1];
                     self.handle_tail_call_global(
                         payload_size as usize,
@@ -2272,6 +2352,7 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
                     // TODO: @Matt -> don't pop the function off of the stack, just read it from there directly.
                     let func = self.thread.stack.pop().unwrap();
                     self.handle_function_call(func, payload_size as usize)?;
@@ -2283,6 +2364,7 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
                     let func = self.thread.stack.pop().unwrap();
                     self.handle_tail_call(func, payload_size as usize)?
                 }
@@ -2303,6 +2385,7 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    self.consume_fuel()?;
                     let current_arity = payload_size as usize;
                     // This is the number of (local) functions we need to pop to get back to the place we want to be at
                     // let depth = self.instructions[self.ip + 1].payload_size as usize;
@@ -2367,6 +2450,10 @@ impl<'a> VmCore<'a> {
                     payload_size,
                     ..
                 } => {
+                    // Only a jump backwards can loop
+                    if payload_size as usize <= self.ip {
+                        self.consume_fuel()?;
+                    }
                     self.ip = payload_size as usize;
                 }
                 DenseInstruction {
@@ -2716,6 +2803,15 @@ impl<'a> VmCore<'a> {
         Ok(())
     }
 
+    // Functions bound with `define` carry their name in the constant map, anonymous
+    // ones point at void.
+    fn closure_name(&self, name_index: usize) -> Option<InternedString> {
+        match self.constants.get(name_index) {
+            SteelVal::SymbolV(name) => Some(name.as_str().into()),
+            _ => None,
+        }
+    }
+
     fn handle_pure_function(&mut self, offset: usize) {
         // println!("Hitting start closure");
 
@@ -2742,8 +2838,12 @@ impl<'a> VmCore<'a> {
 
         self.ip += 1;
 
+        let name_index = self.instructions[self.ip].payload_size as usize;
+
+        self.ip += 1;
+
         // TODO - used to be offset - 2, now 3 with the multi arity
-        let forward_jump = offset - 2;
+        let forward_jump = offset - 3;
         // let forward_jump = offset;
 
         // TODO clean this up a bit
@@ -2792,7 +2892,7 @@ impl<'a> VmCore<'a> {
             // snag the arity from the eclosure instruction
             let arity = self.instructions[forward_index - 1].payload_size;
 
-            let constructed_lambda = Gc::new(ByteCodeLambda::new(
+            let mut lambda = ByteCodeLambda::new(
                 closure_id,
                 closure_body,
                 arity as usize,
@@ -2800,7 +2900,11 @@ impl<'a> VmCore<'a> {
                 Vec::new(),
                 Vec::new(),
                 // Rc::clone(&spans),
-            ));
+            );
+
+            lambda.name = self.closure_name(name_index);
+
+            let constructed_lambda = Gc::new(lambda);
 
             self.thread
                 .function_interner
@@ -2849,9 +2953,13 @@ impl<'a> VmCore<'a> {
 
         self.ip += 1;
 
+        let name_index = self.instructions[self.ip].payload_size as usize;
+
+        self.ip += 1;
+
         // TODO - used to be offset - 2, now 3 with the multi arity
         // let forward_jump = offset;
-        let forward_jump = offset - 3;
+        let forward_jump = offset - 4;
         // println!("Forward jump: {}", forward_jump);
 
         // Snag the number of upvalues here
@@ -3014,6 +3122,8 @@ impl<'a> VmCore<'a> {
                 Vec::new(),
             );
 
+            constructed_lambda.name = self.closure_name(name_index);
+
             self.thread
                 .function_interner
                 .closure_interner
@@ -3134,8 +3244,9 @@ impl<'a> VmCore<'a> {
             //     closure.arity()
             // );
 
+            // The last slot holds the rest argument, so it doesn't count towards the minimum
             if payload_size < closure.arity() - 1 {
-                stop!(ArityMismatch => format!("function expected at least {} arguments, found {}", closure.arity(), payload_size); self.current_span());
+                stop!(ArityMismatch => format!("function expected at least {} arguments, found {}", closure.arity() - 1, payload_size); self.current_span());
             }
 
             // (define (test x . y))
@@ -4346,6 +4457,77 @@ pub(crate) fn set_test_mode(ctx: &mut VmCore, _args: &[SteelVal]) -> Option<Resu
     Some(Ok(ctx.thread.runtime_options.test.into()))
 }
 
+pub(crate) fn assert_continue(ctx: &mut VmCore, args: &[SteelVal]) -> Option<Result<SteelVal>> {
+    // Like `assert!`, the optional second argument is the asserted expression
+    let message = match args {
+        [SteelVal::BoolV(true)] | [SteelVal::BoolV(true), _] => return Some(Ok(SteelVal::Void)),
+        [value] => format!("assertion failed: expected #true, found: {value}"),
+        [_, form] => format!("assertion failed: {form}"),
+        _ => {
+            return Some(Err(SteelErr::new(
+                ErrorKind::ArityMismatch,
+                "assert-continue! takes one argument".to_string(),
+            )))
+        }
+    };
+
+    ctx.thread.failed_assertions.push(message);
+
+    Some(Ok(SteelVal::Void))
+}
+
+pub(crate) fn get_failed_assertions(
+    ctx: &mut VmCore,
+    _args: &[SteelVal],
+) -> Option<Result<SteelVal>> {
+    Some(Ok(SteelVal::ListV(
+        ctx.thread
+            .failed_assertions
+            .iter()
+            .map(|x| SteelVal::StringV(x.as_str().into()))
+            .collect(),
+    )))
+}
+
+fn engine_current_directory(ctx: &VmCore) -> std::io::Result<PathBuf> {
+    match &ctx.thread.current_directory {
+        Some(path) => Ok(path.clone()),
+        None => std::env::current_dir(),
+    }
+}
+
+/// With no arguments, returns the directory that relative requires are resolved against,
+/// which is the working directory of the process unless it has been changed. Given a path,
+/// sets it as the current directory of this engine, leaving the process working directory
+/// alone. Relative paths are resolved against the current directory.
+pub(crate) fn current_directory(ctx: &mut VmCore, args: &[SteelVal]) -> Option<Result<SteelVal>> {
+    let current = match engine_current_directory(ctx) {
+        Ok(current) => current,
+        Err(e) => builtin_stop!(Generic => e),
+    };
+
+    match args {
+        [] => Some(Ok(SteelVal::StringV(current.to_str().unwrap_or("").into()))),
+        [SteelVal::StringV(path)] => {
+            let path = current.join(path.as_str());
+
+            if !path.is_dir() {
+                builtin_stop!(Generic => "current-directory: not a directory: {}", path.display())
+            }
+
+            ctx.thread.current_directory = Some(path);
+
+            Some(Ok(SteelVal::Void))
+        }
+        [other] => {
+            builtin_stop!(TypeMismatch => "current-directory expects a string, found: {}", other)
+        }
+        _ => {
+            builtin_stop!(ArityMismatch => "current-directory takes at most one argument, found {}", args.len())
+        }
+    }
+}
+
 pub(crate) fn list_modules(ctx: &mut VmCore, _args: &[SteelVal]) -> Option<Result<SteelVal>> {
     use crate::rvals::AsRefSteelVal;
     use crate::steel_vm::builtin::BuiltInModule;
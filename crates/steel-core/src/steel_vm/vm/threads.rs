@@ -67,6 +67,7 @@ pub fn closure_into_serializable(
             body_exp: prototype.body_exp,
             arity: prototype.arity,
             is_multi_arity: prototype.is_multi_arity,
+            name: prototype.name,
             captures: Vec::new(),
         };
 
@@ -90,6 +91,7 @@ pub fn closure_into_serializable(
 
             arity: c.arity,
             is_multi_arity: c.is_multi_arity,
+            name: c.name,
         };
 
         CACHED_CLOSURES.with(|x| x.borrow_mut().insert(c.id, prototype.clone()));
@@ -99,6 +101,7 @@ pub fn closure_into_serializable(
             body_exp: prototype.body_exp,
             arity: prototype.arity,
             is_multi_arity: prototype.is_multi_arity,
+            name: prototype.name,
             captures: Vec::new(),
         };
 
@@ -118,6 +121,8 @@ struct MovableThread {
     global_env: Vec<SerializableSteelVal>,
     function_interner: MovableFunctionInterner,
     runtime_options: RunTimeOptions,
+    fuel: Option<u64>,
+    current_directory: Option<PathBuf>,
 }
 
 struct MovableFunctionInterner {
@@ -259,6 +264,10 @@ fn spawn_thread_result(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal>
         ),
 
         runtime_options: ctx.thread.runtime_options.clone(),
+        // Spawned threads get the remaining budget of the parent, so that spawning
+        // can't be used to escape the limit
+        fuel: ctx.thread.fuel,
+        current_directory: ctx.thread.current_directory.clone(),
     };
 
     let sendable_vtable_entries = VTable::sendable_entries(&mut initial_map, &mut visited)?;
@@ -380,6 +389,10 @@ fn spawn_thread_result(ctx: &mut VmCore, args: &[SteelVal]) -> Result<SteelVal>
             current_frame: StackFrame::main(),
             stack_frames: Vec::with_capacity(32),
             constant_map,
+            fuel: thread.fuel,
+            failed_assertions: Vec::new(),
+            current_directory: thread.current_directory,
+            eval_engine: None,
         };
 
         #[cfg(feature = "profiling")]
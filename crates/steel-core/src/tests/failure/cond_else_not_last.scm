@@ -0,0 +1,4 @@
+;; `else` has to be the last clause of a cond
+(cond
+  [else 1]
+  [#t 2])
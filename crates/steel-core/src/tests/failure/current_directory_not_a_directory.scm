@@ -0,0 +1 @@
+(current-directory "this-directory-does-not-exist")
@@ -0,0 +1,8 @@
+(define-record-type point
+                    (make-point x y)
+                    point?
+                    (x point-x set-point-x!)
+                    (y point-y))
+
+;; `y` was not given a modifier, so there is no setter for it
+(set-point-y! (make-point 1 2) 10)
@@ -0,0 +1,6 @@
+(define (foo)
+  (define a (+ b 1))
+  (define b 10)
+  a)
+
+(foo)
@@ -0,0 +1,6 @@
+(let-syntax ([local-macro (syntax-rules ()
+                            [(local-macro x) x])])
+  (local-macro 10))
+
+;; The macro is not visible outside of the let-syntax body
+(local-macro 10)
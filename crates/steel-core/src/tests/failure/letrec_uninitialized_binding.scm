@@ -0,0 +1,6 @@
+(define (foo)
+  (letrec ([a (+ b 1)]
+           [b 10])
+    a))
+
+(foo)
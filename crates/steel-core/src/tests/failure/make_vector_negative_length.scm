@@ -0,0 +1 @@
+(make-vector -1 'a)
@@ -0,0 +1,2 @@
+;; Two formals can't be bound from three values
+(receive (a b) (values 1 2 3) (+ a b))
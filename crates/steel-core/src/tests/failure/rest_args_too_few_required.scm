@@ -0,0 +1,4 @@
+(define (f a b . rest)
+  (list a b rest))
+
+(f 1)
@@ -0,0 +1,3 @@
+;; There is no quasiquote for this unquote to belong to
+(define x 10)
+(list 1 ,x)
@@ -51,50 +51,89 @@ macro_rules! test_harness_failure {
 
 test_harness_success! {
     abc_problem,
+    alists,
     apply_more_complex,
+    assert_continue,
     babbage_problem,
     balanced_brackets,
     basic_apply,
     calculator,
     capture_upvalue,
     capture_upvalues_arity_two,
+    case_arrow,
+    case_eqv,
     close_upvalue,
     closure_value_capture,
     comma_quibbling,
     complex_lets,
+    cond_edge_cases,
+    curried_define,
+    current_directory,
     define_normal,
+    define_record_type,
     defmacro,
     delim_control,
     delim_control_n,
     dfs,
     dll,
     docs,
+    dynamic_wind_native,
     ellipses,
+    else_shadowing,
     empty,
+    eq,
+    eqv_searching,
+    eval_special_forms,
     fib,
+    foldcase,
+    format,
     generator,
     generic_execution,
     generic_execution_dropping,
     generic_execution_output_different_type,
     generic_transducer,
     generic_transducer_with_different_functions,
+    guard,
+    hash_functions,
+    hash_tables,
     heap_sort,
     help,
     html_table,
+    if_let,
+    internal_define,
+    lambda_multiple_body_expressions,
+    let_star,
+    let_syntax,
+    letrec,
     letrec_mutual_recursion,
     letrec_simple_recursion,
     list_functions,
+    local_bindings,
     local_struct,
+    long_list_iteration,
     matcher,
     math,
     maxsubseq,
     merge_sort,
+    multi_list_length_mismatch,
+    multiple_values,
+    named_let,
     ncsubseq,
+    nested_exception_handlers,
     numbers,
+    numeric_tower,
+    parameter_converter,
     pascals,
+    pattern_matching,
+    peek_char,
     permutations,
+    plists,
+    procedure_printing,
+    quasiquote,
     quicksort,
     read,
+    read_string,
+    receive,
     require_alias,
     require_only_in,
     require_prefix,
@@ -110,24 +149,47 @@ test_harness_success! {
     simple_stream_with_mapping,
     simple_stream_with_transduce_operation,
     simple_stream_with_transducer,
+    sorted_hash_entries,
     stack_state,
     stack_struct,
     stack_test_with_contract,
     string_append,
+    string_chars_round_trip,
+    string_split,
+    string_trim,
     structs,
+    symbol_append,
     // TODO: @Matt 11/11/2023
     threads,
     transducer_over_streams,
     tree_traversal,
     trie_sort,
+    two_armed_if,
+    type_predicates,
+    variadic_lambdas,
+    vector_equality,
+    vector_functions,
+    when_unless,
+    with_input_from_string,
+    writeln,
     y_combinator,
 }
 
 test_harness_failure! {
     capped_depth_defmacro,
+    cond_else_not_last,
+    current_directory_not_a_directory,
+    define_record_type_immutable_field,
     function_used_before_definition,
     global_env,
     identifier_used_before_definition,
+    internal_define_forward_reference,
+    let_syntax_macro_inaccessible,
+    letrec_uninitialized_binding,
     local_struct_inaccessible,
+    make_vector_negative_length,
+    receive_arity_mismatch,
     require_only_in_missing_identifier,
+    rest_args_too_few_required,
+    unquote_outside_quasiquote,
 }
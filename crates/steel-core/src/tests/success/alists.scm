@@ -0,0 +1,20 @@
+(define alist (list (cons 'a 1) (cons 'b 2) (cons 'c 3)))
+
+;; Updating an existing key replaces its entry in place
+(assert! (equal? (list (cons 'a 1) (cons 'b 20) (cons 'c 3)) (alist-update alist 'b 20)))
+(assert! (equal? 20 (cdr (assoc 'b (alist-update alist 'b 20)))))
+
+;; Updating a missing key adds it to the end
+(assert! (equal? (list (cons 'a 1) (cons 'b 2) (cons 'c 3) (cons 'd 4)) (alist-update alist 'd 4)))
+(assert! (equal? (list (cons "key" 'value)) (alist-update '() "key" 'value)))
+
+;; Deleting a present key drops every entry for it
+(assert! (equal? (list (cons 'a 1) (cons 'c 3)) (alist-delete 'b alist)))
+(assert! (equal? (list (cons 'b 2)) (alist-delete 'a (list (cons 'a 1) (cons 'b 2) (cons 'a 3)))))
+
+;; Deleting an absent key is a no-op
+(assert! (equal? alist (alist-delete 'missing alist)))
+(assert! (equal? '() (alist-delete 'a '())))
+
+;; The original is unchanged
+(assert! (equal? (list (cons 'a 1) (cons 'b 2) (cons 'c 3)) alist))
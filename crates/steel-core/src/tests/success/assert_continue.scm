@@ -0,0 +1,19 @@
+;; Passing assertions record nothing
+(assert-continue! (= 1 1))
+(assert! (equal? '() (get-failed-assertions)))
+
+;; A failing assertion returns void instead of stopping the program
+(define result (assert-continue! (= 1 2)))
+(assert! (void? result))
+
+(define reached #f)
+(set! reached #t)
+(assert! reached)
+
+;; Failures accumulate in order and mention the asserted expression
+(assert-continue! (equal? "a" "b"))
+
+(define failures (get-failed-assertions))
+(assert! (equal? 2 (length failures)))
+(assert! (equal? "assertion failed: (= 1 2)" (list-ref failures 0)))
+(assert! (starts-with? (list-ref failures 1) "assertion failed: (equal?"))
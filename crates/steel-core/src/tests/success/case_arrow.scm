@@ -0,0 +1,34 @@
+(define (classify x)
+  (case x
+    [(1 2 3) => (lambda (n) (* n 10))]
+    [(a b) => symbol->string]
+    [else => (lambda (other) (list 'other other))]))
+
+;; The matching key is passed to the procedure
+(assert! (equal? 20 (classify 2)))
+(assert! (equal? "b" (classify 'b)))
+
+;; else => receives the key as well
+(assert! (equal? '(other 100) (classify 100)))
+
+;; The key expression is only evaluated once
+(define evaluations 0)
+(define (next-key)
+  (set! evaluations (+ evaluations 1))
+  5)
+
+(assert! (equal? 6
+                 (case (next-key)
+                   [(4 5 6) => (lambda (n) (+ n 1))]
+                   [else 'no-match])))
+(assert! (equal? 1 evaluations))
+
+;; Regular clauses still work alongside => clauses
+(assert! (equal? 'small
+                 (case 1
+                   [(1 2) 'small]
+                   [(3 4) => (lambda (n) n)])))
+(assert! (equal? 'fallback
+                 (case 10
+                   [(1 2) => (lambda (n) n)]
+                   [else 'fallback])))
@@ -0,0 +1,23 @@
+(define (classify x)
+  (case x
+    [(2) 'exact-two]
+    [(#\a #\b) 'early-letter]
+    [(foo) 'foo]
+    [else 'other]))
+
+;; Datums are compared with eqv?, so exactness matters
+(assert! (equal? 'exact-two (classify 2)))
+(assert! (equal? 'other (classify 2.0)))
+(assert! (equal? 'exact-two (classify (+ 1 1))))
+
+;; Characters and symbols match by value
+(assert! (equal? 'early-letter (classify #\b)))
+(assert! (equal? 'early-letter (classify (string-ref "abc" 0))))
+(assert! (equal? 'other (classify #\c)))
+(assert! (equal? 'foo (classify (string->symbol "foo"))))
+
+;; Exact rationals match by value as well
+(assert! (equal? 'half
+                 (case (/ 2 4)
+                   [(1/2) 'half]
+                   [else 'other])))
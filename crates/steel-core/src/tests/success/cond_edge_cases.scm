@@ -0,0 +1,33 @@
+;; An empty cond has nothing to return
+(assert! (void? (cond)))
+
+;; A clause with only a test returns the value of the test
+(assert! (equal? '(2 3) (cond [(member 2 '(1 2 3))])))
+(assert! (equal? 10 (cond [#f 1] [10])))
+
+;; and falls through to the next clause when the test is false
+(assert! (equal? 'fallthrough (cond [(member 4 '(1 2 3))] [else 'fallthrough])))
+(assert! (void? (cond [#f])))
+
+;; The test is only evaluated once
+(define counter 0)
+(define (bump!)
+  (set! counter (+ counter 1))
+  counter)
+(assert! (equal? 1 (cond [(bump!)] [else 'unreachable])))
+(assert! (equal? 1 counter))
+
+;; The arrow form passes the value of the test on to the procedure
+(assert! (equal? 3 (cond [(member 2 '(1 2 3)) => length] [else 0])))
+(assert! (equal? 0 (cond [(member 4 '(1 2 3)) => length] [else 0])))
+(assert! (equal? 4 (cond [#f => (lambda (x) 'unreachable)] [(+ 1 3) => (lambda (x) x)])))
+(assert! (void? (cond [#f => (lambda (x) x)])))
+
+;; The arrow's test is also only evaluated once
+(set! counter 0)
+(assert! (equal? 2 (cond [(bump!) => (lambda (x) (* x 2))])))
+(assert! (equal? 1 counter))
+
+;; else matches unconditionally, and the first true clause wins
+(assert! (equal? 'else (cond [#f 'no] [else 'else])))
+(assert! (equal? 'first (cond [#t 'first] [#t 'second] [else 'else])))
@@ -0,0 +1,13 @@
+(define original (current-directory))
+
+(assert! (is-dir? original))
+
+;; Relative paths are resolved against the current directory
+(current-directory "src")
+
+(assert! (not (equal? (current-directory) original)))
+(assert! (is-file? (string-append (current-directory) "/lib.rs")))
+
+(current-directory original)
+
+(assert! (equal? (current-directory) original))
@@ -0,0 +1,22 @@
+(define ((adder n) x)
+  (+ n x))
+
+(assert! (equal? 7 ((adder 3) 4)))
+
+(define add-ten (adder 10))
+(assert! (equal? 15 (add-ten 5)))
+
+;; Each level can take any number of arguments, and the body can have multiple expressions
+(define (((triple a) b c) d)
+  (define total (+ a b c))
+  (* total d))
+
+(assert! (equal? 60 (((triple 1) 2 3) 10)))
+
+;; Curried defines work for internal definitions as well
+(define (make-scaler factor)
+  (define ((scale offset) x)
+    (+ offset (* factor x)))
+  scale)
+
+(assert! (equal? 21 (((make-scaler 2) 1) 10)))
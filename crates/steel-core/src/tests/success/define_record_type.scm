@@ -0,0 +1,35 @@
+(define-record-type point
+                    (make-point x y)
+                    point?
+                    (x point-x set-point-x!)
+                    (y point-y))
+
+(define-record-type <node>
+                    (leaf value)
+                    node?
+                    (value node-value)
+                    (children node-children set-node-children!))
+
+;; The constructor goes by the name given, not the type name
+(define p (make-point 1 2))
+(assert! (equal? 1 (point-x p)))
+(assert! (equal? 2 (point-y p)))
+
+;; Fields with a modifier can be mutated
+(set-point-x! p 10)
+(assert! (equal? 10 (point-x p)))
+(assert! (equal? 2 (point-y p)))
+
+;; Predicates only accept their own record type
+(assert! (point? p))
+(assert! (not (point? (leaf 1))))
+(assert! (node? (leaf 1)))
+(assert! (not (node? p)))
+(assert! (not (point? '(1 2))))
+
+;; Fields left out of the constructor start out as void
+(define n (leaf 'a))
+(assert! (equal? 'a (node-value n)))
+(assert! (void? (node-children n)))
+(set-node-children! n (list (leaf 'b)))
+(assert! (equal? 'b (node-value (car (node-children n)))))
@@ -0,0 +1,32 @@
+(define entered 0)
+(define exited 0)
+
+(define (checked x)
+  (dynamic-wind (lambda () (set! entered (+ entered 1)))
+                (lambda ()
+                  (if (= x 3)
+                      (error "boom")
+                      x))
+                (lambda () (set! exited (+ exited 1)))))
+
+;; The error escapes from inside of the function passed to `map`, but the
+;; after thunk for the failing element still runs on the way out
+(define result
+  (call-with-exception-handler (lambda (err) 'caught)
+                               (lambda () (map checked (list 1 2 3 4)))))
+
+(assert! (equal? 'caught result))
+(assert! (equal? 3 entered))
+(assert! (equal? 3 exited))
+
+;; Handlers installed within the function passed to `map` catch the error
+;; there, and the mapping continues afterwards
+(set! entered 0)
+(set! exited 0)
+
+(assert! (equal? '(1 2 0 4)
+                 (map (lambda (x) (call-with-exception-handler (lambda (err) 0) (lambda () (checked x))))
+                      (list 1 2 3 4))))
+
+(assert! (equal? 4 entered))
+(assert! (equal? 4 exited))
@@ -0,0 +1,24 @@
+;; `else` is only the catch all clause when it isn't bound to a value
+(assert! (equal? 'default (cond [#f 'first] [else 'default])))
+(assert! (equal? 'default (case 10 [(1 2) 'small] [else 'default])))
+
+;; A parameter named `else` is an ordinary test
+(define (classify else)
+  (cond
+    [else 'else-was-true]
+    [#t 'fell-through]))
+
+(assert! (equal? 'else-was-true (classify 1)))
+(assert! (equal? 'fell-through (classify #f)))
+
+;; As is a `let` binding named `else`, which also doesn't have to be the last clause
+(assert! (equal? 'fell-through
+                 (let ([else #f])
+                   (cond
+                     [else 'else-was-true]
+                     [#t 'fell-through]))))
+
+;; The binding only applies within its scope
+(let ([else #f])
+  (assert! (not else)))
+(assert! (equal? 'default (cond [#f 'first] [else 'default])))
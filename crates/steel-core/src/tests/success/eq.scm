@@ -0,0 +1,33 @@
+;; The empty list and booleans are singletons
+(assert! (eq? '() '()))
+(assert! (eq? '() (list)))
+(assert! (eq? (cdr (list 1)) '()))
+(assert! (eq? #t #t))
+(assert! (eq? #f (not #t)))
+(assert! (eqv? '() '()))
+(assert! (eqv? #f #f))
+
+;; Small immediate values compare by value
+(assert! (eq? 10 (+ 5 5)))
+(assert! (eq? #\a (string-ref "abc" 0)))
+(assert! (eq? 'foo (string->symbol (string-append "f" "oo"))))
+
+;; Objects are only eq? to themselves
+(define v (mutable-vector 1 2 3))
+(assert! (eq? v v))
+(assert! (not (eq? v (mutable-vector 1 2 3))))
+
+(define s (string-append "hello" " world"))
+(assert! (eq? s s))
+(assert! (not (eq? s (string-append "hello" " world"))))
+
+(define l (list 1 2 3))
+(assert! (eq? l l))
+(assert! (not (eq? l (list 1 2 3))))
+
+(define p (cons 1 2))
+(assert! (eq? p p))
+(assert! (not (eq? p (cons 1 2))))
+
+(assert! (not (eq? #t #f)))
+(assert! (not (eq? '() #f)))
@@ -0,0 +1,29 @@
+;; eqv? compares every kind of number by value, while eq? does not for rationals
+(define big (* 99999999999 99999999999 99999999999))
+(define half (/ 1 2))
+
+(assert! (eqv? big (* 99999999999 99999999999 99999999999)))
+(assert! (eqv? half (/ 2 4)))
+(assert! (not (eqv? 2 2.0)))
+(assert! (eqv? #\a (string-ref "cat" 1)))
+
+;; memv / assv find numeric and character elements that memq / assq miss
+(assert! (equal? (memv (/ 2 4) (list 1 half 3)) (list half 3)))
+(assert! (not (memq (/ 2 4) (list 1 half 3))))
+(assert! (equal? (memv #\b '(#\a #\b #\c)) '(#\b #\c)))
+(assert! (equal? (assv (* 99999999999 99999999999 99999999999) (list (list big 'big)))
+                 (list big 'big)))
+(assert! (not (assq (/ 2 4) (list (list half 'half)))))
+(assert! (equal? (assv #\x '((#\x 1) (#\y 2))) '(#\x 1)))
+
+;; ... but miss structurally equal objects that member / assoc find
+(assert! (not (memv (list 1 2) '((1 2) (3 4)))))
+(assert! (equal? (member (list 1 2) '((1 2) (3 4))) '((1 2) (3 4))))
+(assert! (not (memv "abc" (list (string-append "a" "bc")))))
+(assert! (not (assv (list 'a) '(((a) 1)))))
+(assert! (equal? (assoc (list 'a) '(((a) 1))) '((a) 1)))
+
+;; The same object is always found
+(define key (list 'k))
+(assert! (equal? (assv key (list (list key 1))) (list key 1)))
+(assert! (equal? (memq 'c '(a b c d)) '(c d)))
@@ -0,0 +1,25 @@
+;; Expressions built at runtime are compiled just like source, so special form
+;; heads behave the same as their literal counterparts
+(assert! (equal? (if #t 1 2) (eval (list 'if #t 1 2))))
+(assert! (equal? (if #f 1 2) (eval (list 'if #f 1 2))))
+(assert! (equal? (if '() 'truthy 'falsy) (eval (list 'if ''() ''truthy ''falsy))))
+
+(assert! (equal? 11 (eval '(let ([x 10]) (+ x 1)))))
+(assert! (equal? 25 (eval (list (list 'lambda '(x) '(* x x)) 5))))
+(assert! (equal? '(a b) (eval (list 'quote '(a b)))))
+(assert! (equal? 3 (eval (list 'begin 1 2 3))))
+
+;; Macros from the prelude are expanded as well
+(assert! (equal? 'big (eval (list 'cond (list (list '> 10 5) ''big) (list 'else ''small)))))
+(assert! (equal? 'yes (eval (list 'when #t ''yes))))
+
+;; A malformed special form is a syntax error, just as it would be in source
+(define (eval-or-error expr)
+  (guard (e [(error-object? e) 'error])
+    (eval expr)))
+(assert! (equal? 'error (eval-or-error (list 'if))))
+(assert! (equal? 'error (eval-or-error (list 'lambda))))
+
+;; eval doesn't see the bindings of the calling program
+(define only-in-caller 42)
+(assert! (equal? 'error (eval-or-error 'only-in-caller)))
@@ -0,0 +1,18 @@
+;; Folding agrees with downcasing for ASCII
+(assert! (equal? #\a (char-foldcase #\A)))
+(assert! (equal? "hello world" (string-foldcase "Hello World")))
+
+;; Final sigma downcases to itself, but folds to the regular sigma
+(assert! (equal? #\σ (char-foldcase #\ς)))
+(assert! (equal? #\σ (char-foldcase #\Σ)))
+(assert! (equal? #\μ (char-foldcase #\µ)))
+(assert! (char-ci=? #\ς #\Σ))
+(assert! (char-ci=? #\a #\A))
+(assert! (not (char-ci=? #\a #\b)))
+
+;; Sharp s folds to two characters in strings
+(assert! (equal? "strasse" (string-foldcase "Straße")))
+(assert! (not (equal? (string->lower "Straße") (string-foldcase "Straße"))))
+(assert! (string-ci=? "Straße" "STRASSE"))
+(assert! (string-ci=? "ΟΔΟΣ" "οδος"))
+(assert! (string-ci<? "apple" "BANANA"))
@@ -0,0 +1,10 @@
+(assert! (equal? "1 + 2 = 3" (format "~a + ~a = ~a" 1 2 3)))
+(assert! (equal? "hello \"world\"" (format "~a ~s" "hello" "world")))
+(assert! (equal? "line one\nline two" (format "line one~%line two")))
+(assert! (equal? "100~" (format "~a~~" 100)))
+(assert! (equal? "(1 2 3)" (format "~a" (list 1 2 3))))
+(assert! (equal? "#(1 2)" (format "~a" (vector 1 2))))
+(assert! (equal? "#(1 2)" (format "~a" (mutable-vector 1 2))))
+
+;; Writing to a port instead of returning a string
+(assert! (equal? "x = 10\n" (call-with-output-string (lambda (port) (format port "x = ~a~%" 10)))))
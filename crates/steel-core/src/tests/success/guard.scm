@@ -0,0 +1,41 @@
+;; guard catches errors raised by error, exposing the message
+(define message
+  (guard (e [(error-object? e) (error-object-message e)])
+    (error "something went wrong:" 42)
+    'unreachable))
+
+(assert! (equal? "something went wrong: 42" message))
+
+;; ... and failed assertions, whose message includes the asserted expression
+(define assertion-message
+  (guard (e [(error-object? e) (error-object-message e)])
+    (assert! (equal? (+ 1 1) 3))))
+
+(assert! (equal? "assertion failed: (equal? (+ 1 1) 3)" assertion-message))
+
+;; The body's value is returned when nothing is raised
+(assert! (equal? 10 (guard (e [#t 'caught]) (+ 5 5))))
+
+;; Clauses are tried in order, with else as the fallback
+(assert! (equal? 'fallback
+                 (guard (e [(string? e) 'string]
+                           [else 'fallback])
+                   (car '()))))
+
+;; A clause with only a test returns the value of the test
+(assert! (equal? "boom" (guard (e [(error-object-message e)]) (error "boom"))))
+
+;; Without a matching clause, the error propagates to the enclosing handler
+(define outer
+  (guard (outer-error [else 'outer])
+    (guard (inner-error [#f 'inner])
+      (error "not handled here"))))
+
+(assert! (equal? 'outer outer))
+
+;; call-with-exception-handler sees the same error objects
+(define handled
+  (call-with-exception-handler (lambda (err) (error-object? err)) (lambda () (assert! #f))))
+
+(assert! handled)
+(assert! (not (error-object? "not an error")))
@@ -0,0 +1,26 @@
+(define empty (hash))
+(assert! (hash-empty? empty))
+(assert! (equal? 0 (hash-count empty)))
+
+(define one (hash-insert empty 'a 10))
+(assert! (not (hash-empty? one)))
+(assert! (equal? 1 (hash-count one)))
+
+(define two (hash-insert one 'b 20))
+(assert! (equal? 2 (hash-count two)))
+(assert! (equal? (hash-length two) (hash-count two)))
+
+;; Inserting an existing key does not change the count
+(assert! (equal? 2 (hash-count (hash-insert two 'a 30))))
+
+(define removed (hash-remove two 'a))
+(assert! (equal? 1 (hash-count removed)))
+(assert! (not (hash-contains? removed 'a)))
+
+;; The original map is left untouched by the functional update
+(assert! (equal? 2 (hash-count two)))
+
+;; Removing a missing key is a no-op
+(assert! (equal? 1 (hash-count (hash-remove removed 'does-not-exist))))
+
+(assert! (hash-empty? (hash-remove removed 'b)))
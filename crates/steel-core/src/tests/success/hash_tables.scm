@@ -0,0 +1,65 @@
+(define (make-key)
+  (list 1 2 3))
+
+;; equal? keyed tables look up entries by structure
+(define equal-table (make-equal-hash))
+(hash-table-set! equal-table (make-key) 'found)
+(hash-table-set! equal-table (vector 1 2) 'vector)
+
+(assert! (equal? 'found (hash-table-ref equal-table (make-key))))
+(assert! (equal? 'vector (hash-table-ref equal-table (vector 1 2))))
+(assert! (hash-table-contains? equal-table (list 1 2 3)))
+(assert! (equal? 2 (hash-table-count equal-table)))
+
+;; eqv? keyed tables only find the exact same key object
+(define eqv-table (make-eqv-hash))
+(define key (make-key))
+(hash-table-set! eqv-table key 'found)
+
+(assert! (equal? 'found (hash-table-ref eqv-table key)))
+(assert! (not (hash-table-contains? eqv-table (make-key))))
+(assert! (equal? 'missing (hash-table-ref/default eqv-table (make-key) 'missing)))
+
+;; Immediate values are eqv? when they're the same value
+(hash-table-set! eqv-table 10 'ten)
+(hash-table-set! eqv-table 'sym 'symbol)
+(hash-table-set! eqv-table #\a 'char)
+(assert! (equal? 'ten (hash-table-ref eqv-table 10)))
+(assert! (equal? 'symbol (hash-table-ref eqv-table (string->symbol "sym"))))
+(assert! (equal? 'char (hash-table-ref eqv-table #\a)))
+(assert! (equal? 4 (hash-table-count eqv-table)))
+
+(hash-table-delete! eqv-table 10)
+(assert! (not (hash-table-contains? eqv-table 10)))
+
+;; make-hash compares keys with equal?
+(define default-table (make-hash))
+(hash-table-set! default-table (make-key) 'found)
+(assert! (equal? 'found (hash-table-ref default-table (make-key))))
+(assert! (equal? (list (make-key)) (hash-table-keys default-table)))
+
+;; hash-ref! only computes the default when the key is missing
+(define memo (make-hash))
+(define calls 0)
+(define (compute)
+  (set! calls (+ calls 1))
+  'computed)
+
+(assert! (equal? 'computed (hash-ref! memo 'key compute)))
+(assert! (equal? 1 calls))
+(assert! (equal? 'computed (hash-table-ref memo 'key)))
+
+(assert! (equal? 'computed (hash-ref! memo 'key compute)))
+(assert! (equal? 1 calls))
+
+;; Non procedure defaults are inserted as is
+(assert! (equal? 10 (hash-ref! memo 'other 10)))
+(assert! (equal? 10 (hash-ref! memo 'other 20)))
+(assert! (equal? 2 (hash-table-count memo)))
+
+;; equal-hash agrees with equal?
+(assert! (= (equal-hash 42) (equal-hash 42)))
+(assert! (= (equal-hash "hello") (equal-hash (string-append "hel" "lo"))))
+(assert! (= (equal-hash 'sym) (equal-hash (string->symbol "sym"))))
+(assert! (= (equal-hash (list 1 2 (list 3))) (equal-hash (list 1 2 (list 3)))))
+(assert! (int? (equal-hash (vector 1 2 3))))
@@ -0,0 +1,33 @@
+(define table '((a 1) (b 2)))
+(define (lookup k)
+  (assoc k table))
+
+;; The binding is visible in the consequent when the test is truthy
+(assert! (equal? 2 (if-let (x (lookup 'b)) (cadr x) 'missing)))
+(assert! (equal? 'missing (if-let (x (lookup 'c)) (cadr x) 'missing)))
+(assert! (equal? 0 (if-let (x 0) x 'missing)))
+
+;; Without an alternative, a falsy test gives void
+(assert! (void? (if-let (x #f) x)))
+(assert! (equal? 1 (if-let (x (lookup 'a)) (cadr x))))
+
+;; The test is only evaluated once
+(define calls 0)
+(define (next!)
+  (set! calls (+ calls 1))
+  calls)
+(assert! (equal? 1 (if-let (n (next!)) n 'missing)))
+(assert! (equal? 1 calls))
+
+;; The alternative sees the outer binding of the name, not the test value
+(define x 'outer)
+(assert! (equal? 'outer (if-let (x #f) 'inner x)))
+
+;; when-let runs each body expression with the binding in scope
+(define seen '())
+(assert! (equal? 2 (when-let (x (lookup 'b)) (set! seen (cons x seen)) (cadr x))))
+(assert! (equal? '((b 2)) seen))
+
+;; and skips the body entirely when the test is falsy
+(assert! (void? (when-let (x (lookup 'c)) (set! seen '()) x)))
+(assert! (equal? '((b 2)) seen))
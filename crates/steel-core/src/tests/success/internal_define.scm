@@ -0,0 +1,45 @@
+;; A single internal define is a local binding of the body
+(assert! (equal? 2 ((lambda () (define x 1) (+ x 1)))))
+
+(define (one-define)
+  (define x 10)
+  (* x 2))
+(assert! (equal? 20 (one-define)))
+
+;; Several internal defines, where later ones can use earlier ones
+(define (several-defines y)
+  (define a (+ y 1))
+  (define b (* a 2))
+  (define (scale n) (* n b))
+  (scale a))
+(assert! (equal? 24 (several-defines 2)))
+
+;; Internal defines in a let body
+(assert! (equal? 7
+                 (let ([base 3])
+                   (define extra 4)
+                   (+ base extra))))
+
+;; A function can refer to an internal define that comes after it, as long as it
+;; isn't called until that define has been evaluated
+(define (forward-reference n)
+  (define (get-limit) limit)
+  (define limit (* n 10))
+  (get-limit))
+(assert! (equal? 50 (forward-reference 5)))
+
+;; Internal defines are mutually recursive
+(define (parity n)
+  (define (ev? n) (if (= n 0) #t (od? (- n 1))))
+  (define (od? n) (if (= n 0) #f (ev? (- n 1))))
+  (ev? n))
+(assert! (parity 10))
+(assert! (not (parity 7)))
+
+;; They don't leak out of the body, and shadow outer bindings while inside it
+(define x 'outer)
+(define (shadow)
+  (define x 'inner)
+  x)
+(assert! (equal? 'inner (shadow)))
+(assert! (equal? 'outer x))
@@ -0,0 +1,34 @@
+;; Two body expressions: the first runs for its side effect, the last is the value
+(define log '())
+(define (record! x)
+  (set! log (cons x log)))
+
+(define add-one
+  (lambda (x)
+    (record! x)
+    (+ x 1)))
+
+(assert! (equal? 11 (add-one 10)))
+(assert! (equal? '(10) log))
+
+;; Three body expressions run in order before the final value is returned
+(set! log '())
+(define f
+  (lambda (x)
+    (record! 'first)
+    (record! 'second)
+    (* x 2)))
+
+(assert! (equal? 6 (f 3)))
+(assert! (equal? '(second first) log))
+
+;; The final value is computed after the earlier side effects
+(define counter 0)
+(define g
+  (lambda ()
+    (set! counter (+ counter 1))
+    (set! counter (+ counter 1))
+    counter))
+
+(assert! (equal? 2 (g)))
+(assert! (equal? 4 (g)))
@@ -0,0 +1,25 @@
+;; Each binding can see the ones before it
+(assert! (equal? 2 (let* ([a 1] [b (+ a 1)]) b)))
+(assert! (equal? (list 1 2 6)
+                 (let* ([a 1]
+                        [b (+ a 1)]
+                        [c (* 3 b)])
+                   (list a b c))))
+
+;; Later bindings shadow earlier ones with the same name
+(assert! (equal? 11 (let* ([x 1] [x (+ x 10)]) x)))
+
+;; An empty binding list just evaluates the body
+(assert! (equal? 5 (let* () 5)))
+(assert! (equal? 3 (let* () 1 2 3)))
+
+;; Bindings are evaluated left to right, exactly once
+(define order '())
+(define (record! x)
+  (set! order (cons x order))
+  x)
+
+(let* ([a (record! 1)] [b (record! 2)] [c (record! 3)])
+  (+ a b c))
+
+(assert! (equal? (list 3 2 1) order))
@@ -0,0 +1,52 @@
+;; A macro scoped to the body of let-syntax
+(define (swap-pair pair)
+  (let-syntax ([swap! (syntax-rules ()
+                        [(swap! a b)
+                         (let ([tmp a])
+                           (set! a b)
+                           (set! b tmp))])])
+    (define x (car pair))
+    (define y (cadr pair))
+    (swap! x y)
+    (list x y)))
+
+(assert! (equal? '(2 1) (swap-pair '(1 2))))
+
+;; Multiple bindings, and the result of the body is the result of the form
+(assert! (equal? 12
+                 (let-syntax ([double (syntax-rules ()
+                                        [(double x) (* 2 x)])]
+                              [triple (syntax-rules ()
+                                        [(triple x) (* 3 x)])])
+                   (double (triple 2)))))
+
+;; letrec-syntax macros can expand into each other
+(assert! (equal? '(1 2 3)
+                 (letrec-syntax ([my-list (syntax-rules ()
+                                            [(my-list) '()]
+                                            [(my-list x rest ...) (cons x (my-list rest ...))])]
+                                 [my-list-of-three (syntax-rules ()
+                                                     [(my-list-of-three a b c) (my-list a b c)])])
+                   (my-list-of-three 1 2 3))))
+
+;; A local macro shadows a global one inside the body only
+(define-syntax shadowed
+  (syntax-rules ()
+    [(shadowed) 'global]))
+
+(assert! (equal? 'local
+                 (let-syntax ([shadowed (syntax-rules ()
+                                          [(shadowed) 'local])])
+                   (shadowed))))
+
+(assert! (equal? 'global (shadowed)))
+
+;; let-syntax templates see the macros from outside the form, only the body sees the new ones
+(define-syntax foo
+  (syntax-rules ()
+    [(_) 'outer]))
+
+(assert! (equal? '(inner outer)
+                 (let-syntax ([foo (syntax-rules ()
+                                     [(_) (list 'inner (foo))])])
+                   (foo))))
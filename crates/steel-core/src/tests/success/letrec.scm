@@ -0,0 +1,14 @@
+;; Bindings in a letrec can refer to each other from inside lambdas
+(define (parity n)
+  (letrec ([my-even? (lambda (n) (if (= n 0) #t (my-odd? (- n 1))))]
+           [my-odd? (lambda (n) (if (= n 0) #f (my-even? (- n 1))))])
+    (list (my-even? n) (my-odd? n))))
+
+(assert! (equal? (list #t #f) (parity 10)))
+(assert! (equal? (list #f #t) (parity 7)))
+
+;; Deep enough that the mutual recursion has to stay in tail position
+(assert! (equal? (list #t #f) (parity 100000)))
+
+;; Earlier bindings are already initialized when later ones are evaluated
+(assert! (equal? 3 (letrec ([a 1] [b (+ a 2)]) b)))
@@ -19,3 +19,72 @@
 (with-handler (lambda (err) void) (reverse (hash)) (assert! #t))
 (with-handler (lambda (err) void) (length (vector)) (assert! #t))
 (with-handler (lambda (err) void) (empty? 100) (assert! #t))
+
+;; reduce uses the first element as the seed
+(assert! (equal? 10 (reduce + 0 (list 1 2 3 4))))
+(assert! (equal? 42 (reduce + 0 (list 42))))
+(assert! (equal? 'default (reduce + 'default '())))
+(assert! (equal? 4 (reduce max 0 (list 3 1 4 1))))
+
+;; reduce-right associates to the right
+(assert! (equal? '(1 2 3 4 5) (reduce-right append '() (list (list 1 2) (list 3) (list 4 5)))))
+(assert! (equal? '(1 (2 (3 4))) (reduce-right list '() (list 1 2 3 4))))
+(assert! (equal? 'only (reduce-right + 0 (list 'only))))
+(assert! (equal? 0 (reduce-right + 0 '())))
+
+;; zip truncates at the shortest list
+(assert! (equal? '((1 a) (2 b) (3 c)) (zip '(1 2 3) '(a b c))))
+(assert! (equal? '((1 a "x") (2 b "y")) (zip '(1 2 3) '(a b c) '("x" "y"))))
+(assert! (equal? '((1) (2)) (zip '(1 2))))
+(assert! (equal? '() (zip '(1 2 3) '())))
+
+;; unzip
+(assert! (equal? '(1 2 3) (unzip1 '((1 a) (2 b) (3 c)))))
+(assert! (equal? '((1 2 3) (a b c))
+                 (call-with-values (lambda () (unzip2 '((1 a) (2 b) (3 c)))) list)))
+(assert! (equal? '((1 2) (a b)) (call-with-values (lambda () (unzip2 (zip '(1 2) '(a b)))) list)))
+
+;; Improper pairs are not lists, and checking never has to walk a cycle
+(assert! (list? '()))
+(assert! (list? (list 1 2 3)))
+(assert! (not (list? (cons 1 2))))
+(assert! (equal? 3 (length (cons 1 (cons 2 (list 3))))))
+
+;; list-copy copies the spine, keeping the tail of improper lists
+(define dotted (cons 1 (cons 2 3)))
+(define dotted-copy (list-copy dotted))
+(assert! (equal? dotted dotted-copy))
+(assert! (not (eq? dotted dotted-copy)))
+(assert! (equal? 1 (car dotted-copy)))
+(assert! (equal? 2 (car (cdr dotted-copy))))
+(assert! (equal? 3 (cdr (cdr dotted-copy))))
+
+(define proper (list 1 2 3))
+(assert! (equal? proper (list-copy proper)))
+(assert! (not (eq? proper (list-copy proper))))
+(assert! (equal? '() (list-copy '())))
+(assert! (equal? 5 (list-copy 5)))
+
+;; list-tabulate builds a list from the indices
+(assert! (equal? '(0 1 4 9 16) (list-tabulate 5 (lambda (i) (* i i)))))
+(assert! (equal? '() (list-tabulate 0 (lambda (i) i))))
+(assert! (equal? '(0 1 2) (build-list 3 (lambda (i) i))))
+
+;; length+ returns #false instead of erroring on anything that isn't a proper list
+(assert! (equal? 3 (length (list 1 2 3))))
+(assert! (equal? 3 (length+ (list 1 2 3))))
+(assert! (equal? 0 (length+ '())))
+(assert! (equal? #false (length+ (cons 1 2))))
+(assert! (equal? #false (length+ (cons 1 (cons 2 3)))))
+(assert! (equal? #false (length+ 10)))
+
+;; range counts up by default, and down with a negative step
+(assert! (equal? '(0 2 4) (range 0 6 2)))
+(assert! (equal? '(5 4 3 2 1) (range 5 0 -1)))
+(assert! (equal? '() (range 5 0)))
+(assert! (equal? '() (range 0 5 -1)))
+
+;; char-range is inclusive on both ends
+(assert! (equal? '(#\a #\b #\c #\d) (char-range #\a #\d)))
+(assert! (equal? '(#\x) (char-range #\x #\x)))
+(assert! (equal? '() (char-range #\z #\a)))
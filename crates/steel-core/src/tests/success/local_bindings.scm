@@ -0,0 +1,40 @@
+;; The names bound by the innermost let show up along with their values
+(assert! (equal? (list (cons 'a 1) (cons 'b 2)) (let ([a 1] [b 2]) (local-bindings))))
+
+;; Only the innermost frame is reported, not the whole chain
+(assert! (equal? (list (cons 'y 20)) (let ([x 10]) (let ([y (* x 2)]) (local-bindings)))))
+
+;; Function arguments make up the frame of a function body
+(define (bindings-of a b)
+  (local-bindings))
+
+(assert! (equal? (list (cons 'a "one") (cons 'b 'two)) (bindings-of "one" 'two)))
+(assert! (equal? (list (cons 'n 5)) ((lambda (n) (local-bindings)) 5)))
+
+;; The values are read when the form runs
+(define (counter-bindings)
+  (let ([count 0])
+    (set! count (+ count 1))
+    (local-bindings)))
+
+(assert! (equal? (list (cons 'count 1)) (counter-bindings)))
+
+;; With nothing bound locally, the result is empty
+(assert! (equal? '() (local-bindings)))
+
+;; The loop name of a named let isn't one of the bindings
+(assert! (equal? (list (cons 'i 0)) (let loop ([i 0]) (local-bindings))))
+
+;; Definitions in the body belong to the same frame as the arguments
+(define (with-internal-defines x)
+  (define doubled (* x 2))
+  (define (helper) doubled)
+  (map car (local-bindings)))
+
+(assert! (equal? '(x doubled helper) (with-internal-defines 3)))
+
+(define (internal-define-value x)
+  (define doubled (* x 2))
+  (cdr (assoc 'doubled (local-bindings))))
+
+(assert! (equal? 6 (internal-define-value 3)))
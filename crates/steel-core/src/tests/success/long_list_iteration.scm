@@ -0,0 +1,24 @@
+(define big (range 0 1000000))
+
+;; map should not grow the native stack with the length of the list
+(define doubled (map (lambda (x) (* 2 x)) big))
+(assert! (equal? (length doubled) 1000000))
+(assert! (equal? (list-ref doubled 999999) 1999998))
+
+;; for-each over the same list should run in constant stack space
+(define total 0)
+(for-each (lambda (x) (set! total (+ total x))) big)
+(assert! (equal? total 499999500000))
+
+;; multiple lists stop at the shortest one
+(define pairs '())
+(for-each (lambda (a b) (set! pairs (cons (list a b) pairs))) '(1 2 3) '(a b))
+(assert! (equal? pairs '((2 b) (1 a))))
+
+(assert! (equal? (map + '(1 2 3) '(10 20 30)) '(11 22 33)))
+
+;; for-each is only run for its effects, and returns void
+(define (ignore . args) args)
+(assert! (void? (for-each ignore '())))
+(assert! (void? (for-each ignore '(1 2 3))))
+(assert! (void? (for-each ignore '(1 2) '(3 4))))
@@ -0,0 +1,26 @@
+;; Every higher-order list function stops at the end of the shortest list
+
+;; map
+(define mapped (map + '(1 2 3 4) '(10 20)))
+(assert! (equal? '(11 22) mapped))
+(assert! (equal? 2 (length mapped)))
+(assert! (equal? '(11 22) (map + '(10 20) '(1 2 3 4))))
+(assert! (equal? '((1 a x) (2 b y)) (map list '(1 2 3) '(a b c) '(x y))))
+(assert! (equal? '() (map + '() '(1 2 3))))
+(assert! (equal? '() (map + '(1 2 3) '())))
+
+;; for-each
+(define seen '())
+(for-each (lambda (x y) (set! seen (cons (list x y) seen))) '(1 2 3) '(a b))
+(assert! (equal? '((2 b) (1 a)) seen))
+
+;; foldl, foldr and fold
+(assert! (equal? 33 (foldl (lambda (x y acc) (+ x y acc)) 0 '(1 2 3) '(10 20))))
+(assert! (equal? '((2 b) (1 a)) (foldl (lambda (x y acc) (cons (list x y) acc)) '() '(1 2) '(a b c))))
+(assert! (equal? '((1 a) (2 b)) (foldr (lambda (x y acc) (cons (list x y) acc)) '() '(1 2 3) '(a b))))
+(assert! (equal? 33 (fold (lambda (x y acc) (+ x y acc)) 0 '(1 2 3) '(10 20))))
+
+;; The single list forms are unchanged
+(assert! (equal? '(3 2 1) (foldl cons '() '(1 2 3))))
+(assert! (equal? '(1 2 3) (foldr cons '() '(1 2 3))))
+(assert! (equal? 6 (fold + 0 '(1 2 3))))
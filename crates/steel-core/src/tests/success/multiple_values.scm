@@ -0,0 +1,24 @@
+;; Spreading a list out into multiple values
+(assert! (equal? 3 (receive (a b) (list->values '(1 2)) (+ a b))))
+(assert! (equal? '(1 (2 3)) (receive (a . rest) (list->values '(1 2 3)) (list a rest))))
+(assert! (equal? '() (receive all (list->values '()) all)))
+
+;; Collecting multiple values into a list
+(assert! (equal? '(1 2 3) (values->list (lambda () (values 1 2 3)))))
+(assert! (equal? '(1) (values->list (lambda () (values 1)))))
+(assert! (equal? '(42) (values->list (lambda () 42))))
+(assert! (equal? '((1 2) (a b)) (values->list (lambda () (unzip2 '((1 a) (2 b)))))))
+
+;; Round tripping through both bridges
+(assert! (equal? '(a b c) (values->list (lambda () (list->values '(a b c))))))
+(assert! (equal? '(1 2 3) (call-with-values (lambda () (list->values '(1 2 3))) list)))
+
+;; Spreading a vector out into multiple values
+(assert! (equal? 6 (call-with-values (lambda () (vector->values (vector 1 2 3))) +)))
+(assert! (equal? '(1 2 3) (call-with-values (lambda () (vector->values (vector 1 2 3))) list)))
+(assert! (equal? 3 (receive (a b) (vector->values (vector 1 2)) (+ a b))))
+
+;; Only a subrange, either from a start index or between two indices
+(assert! (equal? '(c d e) (call-with-values (lambda () (vector->values (vector 'a 'b 'c 'd 'e) 2)) list)))
+(assert! (equal? '(b c) (call-with-values (lambda () (vector->values (vector 'a 'b 'c 'd 'e) 1 3)) list)))
+(assert! (equal? '() (call-with-values (lambda () (vector->values (vector 1 2 3) 1 1)) list)))
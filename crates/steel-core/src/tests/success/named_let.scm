@@ -0,0 +1,29 @@
+;; The classic loop idiom
+(assert! (equal? 10 (let loop ([i 0]) (if (< i 10) (loop (+ i 1)) i))))
+
+;; Several bindings become several parameters
+(assert! (equal? 55
+                 (let loop ([i 1] [acc 0])
+                   (if (> i 10)
+                       acc
+                       (loop (+ i 1) (+ acc i))))))
+
+;; No bindings at all
+(define count 0)
+(let loop ()
+  (when (< count 5)
+    (set! count (+ count 1))
+    (loop)))
+(assert! (equal? 5 count))
+
+;; The recursive call is a tail call, so deep loops don't grow the stack
+(assert! (equal? 1000000 (let loop ([i 0]) (if (= i 1000000) i (loop (+ i 1))))))
+
+;; The loop can also be used for non-tail recursion
+(assert! (equal? '(0 1 2 3) (let build ([i 0]) (if (= i 4) '() (cons i (build (+ i 1)))))))
+
+;; The name is only bound inside the body, so it can shadow an outer binding
+(define (loop x)
+  'outer)
+(assert! (equal? 3 (let loop ([i 0]) (if (< i 3) (loop (+ i 1)) i))))
+(assert! (equal? 'outer (loop 10)))
@@ -0,0 +1,52 @@
+;; An inner handler that raises again hands the error to the outer handler
+(define result
+  (call-with-exception-handler (lambda (err) (list 'outer (error-object-message err)))
+                               (lambda ()
+                                 (call-with-exception-handler (lambda (err) (raise-error err))
+                                                              (lambda () (error "boom"))))))
+
+(assert! (equal? '(outer "boom") result))
+
+;; A handler is not installed while it runs, so an error raised from inside
+;; of it goes outward instead of calling the same handler again
+(define inner-calls 0)
+(define outer-calls 0)
+
+(define second-result
+  (call-with-exception-handler (lambda (err)
+                                 (set! outer-calls (+ outer-calls 1))
+                                 (error-object-message err))
+                               (lambda ()
+                                 (call-with-exception-handler (lambda (err)
+                                                                (set! inner-calls (+ inner-calls 1))
+                                                                (error "from the inner handler"))
+                                                              (lambda () (error "original"))))))
+
+(assert! (equal? "from the inner handler" second-result))
+(assert! (equal? 1 inner-calls))
+(assert! (equal? 1 outer-calls))
+
+;; Three levels deep, each handler tags the error and passes it along
+(define (tagging-handler tag)
+  (lambda (err) (error (string-append tag " " (error-object-message err)))))
+
+(define third-result
+  (call-with-exception-handler
+   (lambda (err) (error-object-message err))
+   (lambda ()
+     (call-with-exception-handler
+      (tagging-handler "outer")
+      (lambda ()
+        (call-with-exception-handler (tagging-handler "inner") (lambda () (error "boom"))))))))
+
+(assert! (equal? "outer inner boom" third-result))
+
+;; Once the handler has finished, the outer handler is unaffected by the inner one
+(define after
+  (call-with-exception-handler (lambda (err) 'outer)
+                               (lambda ()
+                                 (call-with-exception-handler (lambda (err) 'inner)
+                                                              (lambda () (error "first")))
+                                 (error "second"))))
+
+(assert! (equal? 'outer after))
@@ -0,0 +1,124 @@
+(define (tower-holds? x)
+  (and (if (integer? x) (rational? x) #t)
+       (if (rational? x) (real? x) #t)
+       (if (real? x) (complex? x) #t)
+       (if (complex? x) (number? x) #t)))
+
+(define (all? pred lst)
+  (cond
+    [(null? lst) #t]
+    [(pred (car lst)) (all? pred (cdr lst))]
+    [else #f]))
+
+(define values-to-check (list 0 -7 42 (* 9223372036854775807 10) 3.0 -0.5 (/ 1 3) (/ 6 3) (/ 1.0 0.0) (/ 0.0 0.0)))
+
+(assert! (equal? #t (all? tower-holds? values-to-check)))
+
+;; Integers
+(assert! (integer? 42))
+(assert! (integer? (* 9223372036854775807 10)))
+(assert! (integer? 3.0))
+(assert! (not (integer? 3.5)))
+(assert! (not (integer? (/ 1 3))))
+(assert! (not (integer? (/ 1.0 0.0))))
+
+;; Rationals
+(assert! (rational? (/ 1 3)))
+(assert! (rational? 0.25))
+(assert! (not (rational? (/ 1.0 0.0))))
+(assert! (not (rational? (/ 0.0 0.0))))
+
+;; Every number is real and complex
+(assert! (all? real? values-to-check))
+(assert! (all? complex? values-to-check))
+(assert! (all? number? values-to-check))
+
+;; Non numbers fail at every level
+(assert! (not (number? "10")))
+(assert! (not (complex? 'a)))
+(assert! (not (real? #\1)))
+(assert! (not (integer? (list 1))))
+
+;; Exact integers beyond the fixnum range convert to the nearest float
+(define big (* 9223372036854775807 10))
+(assert! (equal? 92233720368547758070.0 (exact->inexact big)))
+(assert! (equal? -92233720368547758070.0 (exact->inexact (- 0 big))))
+(assert! (inexact? (+ big 0.5)))
+(assert! (< (abs (- (* big 1.0) 92233720368547758070.0)) 1.0))
+
+;; Floats convert to the exact rational they represent
+(assert! (equal? (/ 1 2) (inexact->exact 0.5)))
+(assert! (equal? (/ -1 4) (inexact->exact -0.25)))
+(assert! (equal? (/ 3 8) (inexact->exact 0.375)))
+(assert! (equal? 3 (inexact->exact 3.0)))
+(assert! (exact? (inexact->exact 0.1)))
+(assert! (not (equal? (/ 1 10) (inexact->exact 0.1))))
+(assert! (equal? 0.1 (exact->inexact (inexact->exact 0.1))))
+(assert! (equal? (/ 1 3) (inexact->exact (/ 1 3))))
+
+;; Rounding preserves exactness
+(define five-halves (/ 5 2))
+(assert! (equal? 2 (floor five-halves)))
+(assert! (equal? 3 (ceiling five-halves)))
+(assert! (equal? 2 (truncate five-halves)))
+(assert! (equal? -3 (floor (- 0 five-halves))))
+(assert! (equal? -2 (ceiling (- 0 five-halves))))
+(assert! (equal? -2 (truncate (- 0 five-halves))))
+(assert! (exact? (round five-halves)))
+(assert! (exact? (floor five-halves)))
+
+(assert! (equal? 2.0 (floor 2.5)))
+(assert! (equal? 3.0 (ceiling 2.5)))
+(assert! (equal? -2.0 (truncate -2.7)))
+(assert! (equal? -3.0 (floor -2.5)))
+(assert! (inexact? (round 2.5)))
+(assert! (inexact? (floor 2.5)))
+
+(assert! (equal? 7 (floor 7)))
+(assert! (equal? 7 (ceiling 7)))
+(assert! (equal? 7 (truncate 7)))
+
+;; Rounding leaves infinities and NaN as they are
+(define pos-inf (/ 1.0 0.0))
+(define neg-inf (/ -1.0 0.0))
+(define nan (- pos-inf pos-inf))
+
+(assert! (equal? pos-inf (floor pos-inf)))
+(assert! (equal? pos-inf (ceiling pos-inf)))
+(assert! (equal? pos-inf (truncate pos-inf)))
+(assert! (equal? pos-inf (round pos-inf)))
+(assert! (equal? neg-inf (floor neg-inf)))
+(assert! (equal? neg-inf (ceiling neg-inf)))
+(assert! (equal? neg-inf (truncate neg-inf)))
+(assert! (equal? neg-inf (round neg-inf)))
+
+(define (nan? x)
+  (not (= x x)))
+(assert! (nan? (floor nan)))
+(assert! (nan? (ceiling nan)))
+(assert! (nan? (truncate nan)))
+(assert! (nan? (round nan)))
+
+;; ... but they have no exact counterpart
+(define (exact-or-error x)
+  (guard (e [(error-object? e) 'error])
+    (inexact->exact x)))
+(assert! (equal? 'error (exact-or-error pos-inf)))
+(assert! (equal? 'error (exact-or-error neg-inf)))
+(assert! (equal? 'error (exact-or-error nan)))
+
+;; number->string and string->number round trip, keeping exactness
+(define (round-trips? x)
+  (equal? x (string->number (number->string x))))
+(assert! (round-trips? 42))
+(assert! (round-trips? -1/3))
+(assert! (round-trips? 1.0))
+(assert! (round-trips? 0.1))
+(assert! (round-trips? (/ 1.0 3.0)))
+(assert! (round-trips? 1e-7))
+(assert! (round-trips? 123456789012345678901234567890))
+(assert! (round-trips? pos-inf))
+(assert! (round-trips? neg-inf))
+(assert! (equal? "1.0" (number->string 1.0)))
+(assert! (inexact? (string->number (number->string 1.0))))
+(assert! (nan? (string->number (number->string nan))))
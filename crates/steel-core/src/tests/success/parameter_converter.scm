@@ -0,0 +1,38 @@
+;; The converter runs on the initial value
+(define width
+  (make-parameter "10"
+                  (lambda (x)
+                    (if (string? x)
+                        (string->number x)
+                        x))))
+
+(assert! (equal? 10 (width)))
+
+;; ... and on each parameterize binding
+(assert! (equal? 20 (parameterize ([width "20"]) (width))))
+(assert! (equal? 10 (width)))
+
+(assert! (equal? '(30 40 30)
+                 (parameterize ([width "30"])
+                   (list (width)
+                         (parameterize ([width "40"]) (width))
+                         (width)))))
+
+;; The restored value isn't converted again
+(define conversions 0)
+(define counted
+  (make-parameter 1
+                  (lambda (x)
+                    (set! conversions (+ conversions 1))
+                    (* x 2))))
+
+(assert! (equal? 2 (counted)))
+(assert! (equal? 1 conversions))
+(assert! (equal? 10 (parameterize ([counted 5]) (counted))))
+(assert! (equal? 2 (counted)))
+(assert! (equal? 2 conversions))
+
+;; Without a converter, values are stored as is
+(define plain (make-parameter "10"))
+(assert! (equal? "10" (plain)))
+(assert! (equal? "20" (parameterize ([plain "20"]) (plain))))
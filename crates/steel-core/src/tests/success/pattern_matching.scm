@@ -0,0 +1,57 @@
+;; Destructuring a list binds each element
+(assert! (equal? 6
+                 (match (list 1 2 3)
+                   [(list a b c) (+ a b c)])))
+
+;; The rest of a list can be collected with ...
+(assert! (equal? '(2 3 4)
+                 (match (list 1 2 3 4)
+                   [(list first rest ...) rest])))
+
+;; Pairs destructure into their car and cdr
+(assert! (equal? '(1 (2 3))
+                 (match (list 1 2 3)
+                   [(cons head tail) (list head tail)])))
+(assert! (equal? '(1 2)
+                 (match (cons 1 2)
+                   [(cons a b) (list a b)])))
+(assert! (equal? 'empty
+                 (match '()
+                   [(cons a b) 'pair]
+                   [_ 'empty])))
+
+;; Literals only match themselves
+(define (describe x)
+  (match x
+    [10 'ten]
+    ["ten" 'string-ten]
+    ['ten 'symbol-ten]
+    [_ 'unknown]))
+
+(assert! (equal? 'ten (describe 10)))
+(assert! (equal? 'string-ten (describe "ten")))
+(assert! (equal? 'symbol-ten (describe 'ten)))
+(assert! (equal? 'unknown (describe 11)))
+
+;; The wildcard matches anything without binding it
+(assert! (equal? 'second
+                 (match (list 1 2)
+                   [(list _ 2) 'second]
+                   [_ 'other])))
+
+;; Guards are checked after the pattern matches
+(define (sign x)
+  (match x
+    [n #:when (< n 0) 'negative]
+    [0 'zero]
+    [_ 'positive]))
+
+(assert! (equal? 'negative (sign -5)))
+(assert! (equal? 'zero (sign 0)))
+(assert! (equal? 'positive (sign 5)))
+
+;; A clause whose body is #false still counts as a match
+(assert! (equal? #false
+                 (match 1
+                   [1 #false]
+                   [_ 'fell-through])))
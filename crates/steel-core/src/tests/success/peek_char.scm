@@ -0,0 +1,23 @@
+(define port (open-input-string "abλ"))
+
+;; Peeking doesn't consume the character
+(assert! (equal? #\a (peek-char port)))
+(assert! (equal? #\a (peek-char port)))
+(assert! (equal? #\a (read-char port)))
+
+;; Interleaving peek and read
+(assert! (equal? #\b (peek-char port)))
+(assert! (equal? #\b (read-char port)))
+
+;; Multi byte characters are looked at as a whole
+(assert! (equal? #\λ (peek-char port)))
+(assert! (equal? #\λ (read-char port)))
+
+;; Peeking at the end of the port gives the eof object, and keeps giving it
+(assert! (eof-object? (peek-char port)))
+(assert! (eof-object? (peek-char port)))
+(assert! (eof-object? (read-char port)))
+
+(assert! (eof-object? (peek-char (open-input-string ""))))
+(assert! (not (eof-object? #\a)))
+(assert! (input-port? (open-input-string "")))
@@ -0,0 +1,23 @@
+(define plist '(a 1 b 2 c 3))
+
+;; Lookup
+(assert! (equal? 1 (plist-get plist 'a)))
+(assert! (equal? 3 (plist-get plist 'c)))
+
+;; Values are never mistaken for keys
+(assert! (not (plist-get '(a b b c) 'c)))
+(assert! (equal? 'c (plist-get '(a b b c) 'b)))
+
+;; Missing keys
+(assert! (equal? #f (plist-get plist 'missing)))
+(assert! (equal? 0 (plist-get plist 'missing 0)))
+(assert! (equal? 'none (plist-get '() 'a 'none)))
+
+;; Updating returns a new plist, replacing existing entries in place
+(assert! (equal? '(a 1 b 20 c 3) (plist-put plist 'b 20)))
+(assert! (equal? '(a 1 b 2 c 3 d 4) (plist-put plist 'd 4)))
+(assert! (equal? '(a 1) (plist-put '() 'a 1)))
+(assert! (equal? 20 (plist-get (plist-put plist 'b 20) 'b)))
+
+;; The original is unchanged
+(assert! (equal? '(a 1 b 2 c 3) plist))
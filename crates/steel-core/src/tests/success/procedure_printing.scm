@@ -0,0 +1,16 @@
+(define (render value)
+  (with-output-to-string (lambda () (display value))))
+
+;; Functions bound with define print with their name
+(define (add-one x)
+  (+ x 1))
+
+(assert! (equal? (render add-one) "#<procedure:add-one>"))
+
+;; Anonymous lambdas have no name to show
+(assert! (equal? (render (lambda (x) x)) "#<procedure>"))
+
+;; Builtins print with their registered name
+(assert! (equal? (render car) "#<procedure:car>"))
+
+(assert! (equal? (render (void)) "#<void>"))
@@ -0,0 +1,16 @@
+;; Literal parts of the template stay quoted
+(assert! (equal? '(a b c) `(a b c)))
+(assert! (equal? 'foo `foo))
+(assert! (equal? '(1 (2 3)) `(1 (2 3))))
+
+;; Unquote evaluates its contents, unquote-splicing splices the resulting list in
+(assert! (equal? '(1 2 3 4 5) `(1 ,(+ 1 1) ,@(list 3 4) 5)))
+(assert! (equal? '(1 2) `(1 ,@(list) 2)))
+(assert! (equal? '(1 2 3) `(,@(list 1 2) ,(+ 1 2))))
+(assert! (equal? 10 `,(* 2 5)))
+
+(define xs '(b c))
+(assert! (equal? '(a (b c) b c (d (b c))) `(a ,xs ,@xs (d ,xs))))
+
+;; Unquotes inside a nested quasiquote are left alone
+(assert! (equal? '(1 (quasiquote (2 (unquote (+ 1 1))))) `(1 `(2 ,(+ 1 1)))))
@@ -0,0 +1,20 @@
+(define port (open-input-string "hello world"))
+
+;; Reads exactly k characters when there are enough
+(assert! (equal? "hello" (read-string 5 port)))
+(assert! (equal? #\space (read-char port)))
+
+;; Returns fewer than k at the end of the input
+(assert! (equal? "world" (read-string 100 port)))
+
+;; And the eof object once nothing is left
+(assert! (eof-object? (read-string 5 port)))
+
+;; Reading zero characters gives an empty string
+(assert! (equal? "" (read-string 0 (open-input-string "abc"))))
+
+;; Multi-byte characters count as one character each
+(assert! (equal? "λ🦀" (read-string 2 (open-input-string "λ🦀!"))))
+
+;; Defaults to the current input port
+(assert! (equal? "ab" (with-input-from-string "abc" (lambda () (read-string 2)))))
@@ -0,0 +1,15 @@
+;; Fixed formals
+(assert! (equal? 9 (receive (q r) (values 7 2) (+ q r))))
+(assert! (equal? 1 (receive (x) (values 1) x)))
+
+;; Rest formals collect whatever is left over
+(assert! (equal? '(1 2 (3 4)) (receive (a b . rest) (values 1 2 3 4) (list a b rest))))
+(assert! (equal? '(1 2 ()) (receive (a b . rest) (values 1 2) (list a b rest))))
+(assert! (equal? '(1 2 3) (receive all (values 1 2 3) all)))
+
+;; The body can have multiple expressions
+(assert! (equal? '((1 2) (a b))
+                 (receive (firsts seconds)
+                          (unzip2 '((1 a) (2 b)))
+                          (define pair (list firsts seconds))
+                          pair)))
@@ -0,0 +1,23 @@
+(define table (hash 'pear 3 'apple 1 'fig 2))
+
+;; Sorted entries come back ordered by key
+(assert! (equal? (list (cons 'apple 1) (cons 'fig 2) (cons 'pear 3)) (hash->list table #true)))
+
+;; Unsorted entries contain the same pairs in some order
+(define unsorted (hash->list table))
+(assert! (equal? 3 (length unsorted)))
+(assert! (not (not (member (cons 'fig 2) unsorted))))
+
+;; Keys of different types are grouped: booleans, numbers, chars, strings, symbols,
+;; then everything else
+(define mixed (hash 'sym 0 "str" 1 #\c 2 10 3 -4 4 #t 5 #f 6 (list 1) 7))
+(assert! (equal? (list #f #t -4 10 #\c "str" 'sym (list 1)) (map car (hash->list mixed #true))))
+
+;; hash-for-each visits entries in the same order when sorted
+(define seen '())
+(hash-for-each table (lambda (k v) (set! seen (cons (list k v) seen))) #true)
+(assert! (equal? '((apple 1) (fig 2) (pear 3)) (reverse seen)))
+
+(define total 0)
+(hash-for-each table (lambda (k v) (set! total (+ total v))))
+(assert! (equal? 6 total))
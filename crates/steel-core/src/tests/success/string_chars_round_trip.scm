@@ -0,0 +1,15 @@
+;; Control characters and characters outside of the basic multilingual plane
+;; survive a round trip through a list of characters
+(define original "tab\there\nnew line 🦀!")
+(define chars (string->list original))
+
+(assert! (equal? original (list->string chars)))
+(assert! (equal? 20 (length chars)))
+(assert! (equal? #\tab (list-ref chars 3)))
+(assert! (equal? #\newline (list-ref chars 8)))
+(assert! (equal? #\🦀 (list-ref chars 18)))
+
+;; Building strings directly from characters preserves them too
+(assert! (equal? "\t\n🦀" (string #\tab #\newline #\🦀)))
+(assert! (equal? "\t\n🦀" (list->string (list #\tab #\newline #\🦀))))
+(assert! (equal? 3 (length (string->list (string #\tab #\newline #\🦀)))))
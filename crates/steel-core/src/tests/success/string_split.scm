@@ -0,0 +1,29 @@
+;; Splitting on whitespace by default
+(assert! (equal? '("foo" "bar" "baz") (string-split "foo bar baz")))
+(assert! (equal? '("foo" "bar") (string-split "  foo \t\n bar  ")))
+(assert! (equal? '() (string-split "")))
+(assert! (equal? '() (string-split "   ")))
+
+;; Literal separators still work
+(assert! (equal? '("a" "b" "c") (string-split "a,b,c" #\,)))
+(assert! (equal? '("a" "b" "c") (string-split "a::b::c" "::")))
+(assert! (equal? '("abc") (string-split "abc" #\,)))
+
+;; Splitting on a predicate splits at every character satisfying it
+(assert! (equal? '("a" "b" "c") (string-split "a1b2c" char-numeric?)))
+(assert! (equal? '("foo" "bar") (string-split "foo-bar" (lambda (c) (equal? c #\-)))))
+
+;; The predicate is called once per character
+(define calls 0)
+(string-split "abcde"
+              (lambda (c)
+                (set! calls (+ calls 1))
+                #f))
+(assert! (equal? 5 calls))
+
+;; Consecutive separators collapse into one, and leading or trailing ones are ignored
+(assert! (equal? '("a" "b") (string-split "a123b" char-numeric?)))
+(assert! (equal? '("a" "b") (string-split "1a2b3" char-numeric?)))
+(assert! (equal? '("a" "b") (string-split ",,a,,,b," #\,)))
+(assert! (equal? '("a" "b") (string-split "::a::::b" "::")))
+(assert! (equal? '() (string-split "123" char-numeric?)))
@@ -0,0 +1,26 @@
+;; Whitespace is trimmed by default
+(assert! (equal? "foo" (string-trim "   foo  ")))
+(assert! (equal? "foo  " (string-trim-left "   foo  ")))
+(assert! (equal? "   foo" (string-trim-right "   foo  ")))
+(assert! (equal? "a b" (string-trim "\t\n a b \n")))
+(assert! (equal? "foo" (string-trim "foo")))
+
+;; Strings made up entirely of whitespace trim down to nothing
+(assert! (equal? "" (string-trim "    ")))
+(assert! (equal? "" (string-trim-left " \t\n")))
+(assert! (equal? "" (string-trim-right " \t\n")))
+(assert! (equal? "" (string-trim "")))
+
+;; A single character to trim
+(assert! (equal? "foo" (string-trim "xxfooxx" #\x)))
+(assert! (equal? "fooxx" (string-trim-left "xxfooxx" #\x)))
+(assert! (equal? "xxfoo" (string-trim-right "xxfooxx" #\x)))
+
+;; A string holding the set of characters to trim
+(assert! (equal? "foo" (string-trim "-=foo=-" "=-")))
+(assert! (equal? " foo " (string-trim "** foo **" "*")))
+
+;; A predicate deciding which characters to trim
+(assert! (equal? "foo" (string-trim "123foo45" char-digit?)))
+(assert! (equal? "foo45" (string-trim-left "123foo45" char-digit?)))
+(assert! (equal? "λx" (string-trim "  λx  " (lambda (c) (equal? c #\space)))))
@@ -0,0 +1,10 @@
+(assert! (equal? 'foobar (symbol-append 'foo 'bar)))
+(assert! (equal? 'a-b-c (symbol-append 'a '- 'b '- 'c)))
+
+;; The result is the same symbol as one spelled out in the source
+(assert! (eq? 'foobar (symbol-append 'foo 'bar)))
+(assert! (eq? (symbol-append 'foo 'bar) (string->symbol "foobar")))
+
+;; With no arguments, the result is the empty symbol
+(assert! (equal? "" (symbol->string (symbol-append))))
+(assert! (symbol? (symbol-append)))
@@ -0,0 +1,20 @@
+;; Without an else branch, a true test yields the then branch
+(assert! (equal? 'yes (if #t 'yes)))
+(assert! (equal? 10 (if (> 2 1) (* 5 2))))
+
+;; ... and a false test yields void
+(assert! (void? (if #f 'yes)))
+(assert! (void? (if (< 2 1) (* 5 2))))
+
+;; The then branch isn't evaluated when the test is false
+(define evaluated #f)
+(if #f (set! evaluated #t))
+(assert! (not evaluated))
+
+;; #f, void and the empty list are false, everything else is true
+(assert! (void? (if '() 'yes)))
+(assert! (equal? 'yes (if 0 'yes)))
+
+;; The three armed form is unchanged
+(assert! (equal? 'no (if #f 'yes 'no)))
+(assert! (equal? 'yes (if #t 'yes 'no)))
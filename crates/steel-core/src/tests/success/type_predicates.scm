@@ -0,0 +1,23 @@
+;; Each predicate only holds for its own kind of value
+(define eof (read-char (open-input-string "")))
+
+(assert! (hash-table? (hash 'a 10)))
+(assert! (hash-table? (hash)))
+(assert! (not (hash-table? (hashset 10))))
+(assert! (not (hash-table? '((a . 10)))))
+(assert! (not (hash-table? (vector 1 2))))
+
+(assert! (vector? (vector 1 2 3)))
+(assert! (vector? (vector)))
+(assert! (not (vector? (list 1 2 3))))
+(assert! (not (vector? "abc")))
+(assert! (not (vector? (hash 'a 10))))
+
+(assert! (eof-object? eof))
+(assert! (not (eof-object? #\a)))
+(assert! (not (eof-object? "")))
+(assert! (not (eof-object? '())))
+
+;; `hash-table?` agrees with `hash?`
+(assert! (equal? (hash? (hash 'a 10)) (hash-table? (hash 'a 10))))
+(assert! (equal? (hash? (vector)) (hash-table? (vector))))
@@ -0,0 +1,27 @@
+;; Required arguments followed by a rest parameter
+(define (f a b . rest)
+  (list a b rest))
+
+;; No extra arguments binds the empty list
+(assert! (equal? '(1 2 ()) (f 1 2)))
+
+;; Several extra arguments are collected in order
+(assert! (equal? '(1 2 (3 4 5)) (f 1 2 3 4 5)))
+
+;; The same thing with an explicit lambda
+(define g (lambda (a . rest) (cons a rest)))
+(assert! (equal? '(1) (g 1)))
+(assert! (equal? '(1 2 3) (g 1 2 3)))
+
+;; A bare parameter list takes every argument
+(define h (lambda args args))
+(assert! (equal? '() (h)))
+(assert! (equal? '(1 2 3) (h 1 2 3)))
+
+(define (count-args . args)
+  (length args))
+(assert! (equal? 0 (count-args)))
+(assert! (equal? 4 (count-args 'a 'b 'c 'd)))
+
+;; Rest arguments work with apply
+(assert! (equal? '(1 2 (3 4)) (apply f '(1 2 3 4))))
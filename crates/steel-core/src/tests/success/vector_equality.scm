@@ -0,0 +1,21 @@
+;; equal? compares vectors element by element
+(assert! (equal? (vector 1 2 3) (vector 1 2 3)))
+(assert! (not (equal? (vector 1 2 3) (vector 1 2 4))))
+(assert! (not (equal? (vector 1 2 3) (vector 1 2))))
+(assert! (equal? (vector) (vector)))
+
+(assert! (equal? (mutable-vector 1 2 3) (mutable-vector 1 2 3)))
+(assert! (not (equal? (mutable-vector 1 2 3) (mutable-vector 1 2 4))))
+(assert! (not (equal? (mutable-vector 1 2 3) (mutable-vector 1 2))))
+(assert! (not (equal? (mutable-vector 1 2) (mutable-vector 1 2 3))))
+
+;; Nested vectors inside lists, and lists inside vectors
+(assert! (equal? (list (vector 1 (list 2 3)) (vector "a"))
+                 (list (vector 1 (list 2 3)) (vector "a"))))
+(assert! (not (equal? (list (vector 1 (list 2 3))) (list (vector 1 (list 2 4))))))
+(assert! (equal? (mutable-vector (mutable-vector 1) (list 2))
+                 (mutable-vector (mutable-vector 1) (list 2))))
+
+;; Equal vectors find each other as hash keys
+(define table (hash (vector 1 2) 'found))
+(assert! (equal? (hash-ref table (vector 1 2)) 'found))
@@ -0,0 +1,129 @@
+;; vector-copy
+(assert! (equal? (vector 1 2 3) (vector-copy (vector 1 2 3))))
+(assert! (equal? (vector 2 3) (vector-copy (vector 1 2 3 4) 1 3)))
+(assert! (equal? (vector 3 4) (vector-copy (vector 1 2 3 4) 2)))
+(assert! (equal? (vector) (vector-copy (vector 1 2 3 4) 2 2)))
+
+(define original (mutable-vector 1 2 3 4))
+(define copied (vector-copy original 1 3))
+(vector-set! copied 0 100)
+(assert! (equal? '(100 3) (mutable-vector->list copied)))
+;; The source is untouched by updates to the copy
+(assert! (equal? '(1 2 3 4) (mutable-vector->list original)))
+
+;; vector-copy! between two vectors
+(define destination (mutable-vector 0 0 0 0 0))
+(vector-copy! destination 1 (vector 1 2 3))
+(assert! (equal? '(0 1 2 3 0) (mutable-vector->list destination)))
+
+(vector-copy! destination 0 (mutable-vector 7 8 9) 1)
+(assert! (equal? '(8 9 2 3 0) (mutable-vector->list destination)))
+
+;; Overlapping copy, shifting elements to the right
+(define shift-right (mutable-vector 1 2 3 4 5))
+(vector-copy! shift-right 1 shift-right 0 4)
+(assert! (equal? '(1 1 2 3 4) (mutable-vector->list shift-right)))
+
+;; Overlapping copy, shifting elements to the left
+(define shift-left (mutable-vector 1 2 3 4 5))
+(vector-copy! shift-left 0 shift-left 1)
+(assert! (equal? '(2 3 4 5 5) (mutable-vector->list shift-left)))
+
+;; vector-append
+(assert! (equal? (vector) (vector-append)))
+(assert! (equal? (vector 1 2 3 4 5 6) (vector-append (vector 1 2) (vector 3) (vector) (vector 4 5 6))))
+(assert! (equal? '(1 2 3 4) (mutable-vector->list (vector-append (mutable-vector 1 2) (vector 3 4)))))
+
+;; string->vector and vector->string
+(assert! (equal? (vector #\a #\b #\c) (string->vector "abc")))
+(assert! (equal? (vector #\b #\c) (string->vector "abcd" 1 3)))
+(assert! (equal? (vector #\c #\d) (string->vector "abcd" 2)))
+(assert! (equal? (vector #\λ #\x) (string->vector "aλx" 1)))
+(assert! (equal? "abc" (vector->string (vector #\a #\b #\c))))
+(assert! (equal? "bc" (vector->string (vector #\a #\b #\c #\d) 1 3)))
+(assert! (equal? "cd" (vector->string (mutable-vector #\a #\b #\c #\d) 2)))
+(assert! (equal? "hello world" (vector->string (string->vector "hello world"))))
+(assert! (equal? "" (vector->string (string->vector ""))))
+
+;; vector-binary-search
+(define sorted (vector 1 3 5 7 9 11))
+(assert! (equal? 0 (vector-binary-search sorted 1 -)))
+(assert! (equal? 2 (vector-binary-search sorted 5 -)))
+(assert! (equal? 5 (vector-binary-search sorted 11 -)))
+(assert! (not (vector-binary-search sorted 4 -)))
+(assert! (not (vector-binary-search sorted 12 -)))
+(assert! (not (vector-binary-search (vector) 1 -)))
+
+;; The comparator is only called a logarithmic number of times
+(define comparisons 0)
+(define (counting-compare a b)
+  (set! comparisons (+ comparisons 1))
+  (- a b))
+(define large (apply vector (range 0 1024)))
+(assert! (equal? 700 (vector-binary-search large 700 counting-compare)))
+(assert! (<= comparisons 11))
+
+;; vector-sort returns a new sorted vector and leaves the input alone
+(define unsorted (vector 5 3 9 1 4))
+(assert! (equal? (vector 1 3 4 5 9) (vector-sort unsorted <)))
+(assert! (equal? (vector 9 5 4 3 1) (vector-sort unsorted >)))
+(assert! (equal? (vector 5 3 9 1 4) unsorted))
+(assert! (equal? (vector) (vector-sort (vector) <)))
+
+(define unsorted-mutable (mutable-vector 5 3 9 1 4))
+(define sorted-mutable (vector-sort unsorted-mutable <))
+(assert! (equal? '(1 3 4 5 9) (mutable-vector->list sorted-mutable)))
+(assert! (equal? '(5 3 9 1 4) (mutable-vector->list unsorted-mutable)))
+
+;; Sorting is stable
+(define pairs (vector '(2 a) '(1 b) '(2 c) '(1 d)))
+(assert! (equal? (vector '(1 b) '(1 d) '(2 a) '(2 c))
+                 (vector-sort pairs (lambda (l r) (< (car l) (car r))))))
+
+;; vector-sort! sorts in place
+(define in-place (mutable-vector 3 1 2))
+(vector-sort! in-place <)
+(assert! (equal? '(1 2 3) (mutable-vector->list in-place)))
+
+;; make-vector fills with 0 by default
+(assert! (equal? '(0 0 0) (mutable-vector->list (make-vector 3))))
+(assert! (equal? '() (mutable-vector->list (make-vector 0))))
+(assert! (equal? '(a a) (mutable-vector->list (make-vector 2 'a))))
+
+;; A mutable fill is shared between every slot
+(define shared-fill (mutable-vector 1))
+(define filled (make-vector 3 shared-fill))
+(vector-set! shared-fill 0 100)
+(assert! (equal? 100 (mut-vector-ref (mut-vector-ref filled 0) 0)))
+(assert! (equal? 100 (mut-vector-ref (mut-vector-ref filled 2) 0)))
+(assert! (eq? (mut-vector-ref filled 0) (mut-vector-ref filled 1)))
+
+;; vector-resize copies into a new vector, filling any new slots
+(define small (mutable-vector 1 2 3))
+(define grown (vector-resize small 5 'x))
+(assert! (equal? '(1 2 3 x x) (mutable-vector->list grown)))
+(assert! (equal? '(1 2 3 0) (mutable-vector->list (vector-resize small 4))))
+(assert! (equal? '(1 2) (mutable-vector->list (vector-resize small 2))))
+
+;; Resizing to the same length is a copy
+(define same (vector-resize small 3))
+(assert! (equal? '(1 2 3) (mutable-vector->list same)))
+(vector-set! same 0 100)
+(assert! (equal? '(1 2 3) (mutable-vector->list small)))
+
+;; Immutable vectors stay immutable
+(assert! (equal? (vector 1 2 #f) (vector-resize (vector 1 2) 3 #f)))
+(assert! (equal? (vector 1) (vector-resize (vector 1 2) 1)))
+(assert! (equal? (vector) (vector-resize (vector 1 2) 0)))
+
+;; vector-index and vector-find search with a predicate
+(assert! (equal? 0 (vector-index even? (vector 2 3 5))))
+(assert! (equal? 2 (vector-index even? (vector 1 3 4 6))))
+(assert! (equal? 3 (vector-index even? (vector 1 3 5 8))))
+(assert! (equal? #f (vector-index even? (vector 1 3 5))))
+(assert! (equal? #f (vector-index even? (vector))))
+
+(assert! (equal? 4 (vector-find even? (vector 1 3 4 6))))
+(assert! (equal? 'a (vector-find symbol? (vector 1 'a 'b))))
+(assert! (equal? #f (vector-find even? (vector 1 3 5))))
+(assert! (equal? #f (vector-find even? (vector))))
@@ -0,0 +1,58 @@
+;; `when` returns the value of the last body expression if the condition holds
+(assert! (equal? 3 (when #t 1 2 3)))
+(assert! (equal? "taken" (when (= 1 1) "taken")))
+
+;; and void when it doesn't, never #f
+(assert! (void? (when #f 1 2 3)))
+(assert! (equal? void (when (= 1 2) "skipped")))
+(assert! (not (equal? #f (when #f #t))))
+
+;; `unless` is the mirror image
+(assert! (equal? 3 (unless #f 1 2 3)))
+(assert! (void? (unless #t 1 2 3)))
+(assert! (not (equal? #f (unless #t #t))))
+
+;; and treats the same values as false that `if` does
+(assert! (equal? 'ran (unless '() 'ran)))
+(assert! (equal? 'ran (unless (void) 'ran)))
+
+;; Both agree with a one-armed `if`
+(assert! (equal? (if #f 10) (when #f 10)))
+
+;; A local binding named `void` doesn't leak into the skipped branch
+(assert! (void? (let ([void 10]) (when #f 1))))
+(assert! (void? (let ([void 10]) (unless #t 1))))
+
+;; The body is skipped entirely when the guard fails
+(define effects '())
+(define (record! x)
+  (set! effects (cons x effects))
+  x)
+
+(when #f (record! 'when-skipped) (record! 'when-skipped-again))
+(unless #t (record! 'unless-skipped))
+(assert! (equal? '() effects))
+
+;; and every body expression runs, in order, when it passes
+(assert! (equal? 'second (when #t (record! 'first) (record! 'second))))
+(assert! (equal? 'fourth (unless #f (record! 'third) (record! 'fourth))))
+(assert! (equal? '(fourth third second first) effects))
+
+;; The guard is evaluated exactly once
+(define guard-calls 0)
+(define (guard! value)
+  (set! guard-calls (+ guard-calls 1))
+  value)
+(when (guard! #t) 'ok)
+(unless (guard! #t) 'ok)
+(assert! (equal? 2 guard-calls))
+
+;; The last body expression is in tail position
+(define (count-down n)
+  (when (> n 0)
+    (count-down (- n 1))))
+(define (count-down-unless n)
+  (unless (= n 0)
+    (count-down-unless (- n 1))))
+(assert! (void? (count-down 100000)))
+(assert! (void? (count-down-unless 100000)))
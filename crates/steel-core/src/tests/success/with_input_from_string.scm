@@ -0,0 +1,37 @@
+(define original-port (current-input-port))
+
+;; read consumes one datum at a time from the string
+(define data (with-input-from-string "(1 2 3) foo \"bar\""
+                                     (lambda ()
+                                       (let* ([first (read)] [second (read)] [third (read)])
+                                         (list first second third)))))
+
+(assert! (equal? data '((1 2 3) foo "bar")))
+
+;; read-char and read-line see the same buffer
+(define chars-and-lines
+  (with-input-from-string "ab\nsecond line\nthird"
+                          (lambda ()
+                            (let* ([a (read-char)]
+                                   [b (read-char)]
+                                   [rest (read-line)]
+                                   [second (read-line)]
+                                   [third (read-line)]
+                                   [done (read-line)])
+                              (list a b rest second third (eof-object? done))))))
+
+(assert! (equal? chars-and-lines (list #\a #\b "" "second line" "third" #t)))
+
+;; the thunk's result is returned
+(assert! (equal? 42 (with-input-from-string "" (lambda () 42))))
+
+;; the previous input port is restored afterwards
+(assert! (eq? original-port (current-input-port)))
+
+(define inner-port #f)
+(with-input-from-string "x" (lambda () (set! inner-port (current-input-port))))
+(assert! (not (eq? original-port inner-port)))
+(assert! (eq? original-port (current-input-port)))
+
+;; call-with-input-string passes the port explicitly
+(assert! (equal? #\h (call-with-input-string "hello" read-char)))
@@ -0,0 +1,20 @@
+;; displayln prints with display semantics, followed by a newline
+(assert! (equal? "hello\n" (with-output-to-string (lambda () (displayln "hello")))))
+(assert! (equal? "10\n" (with-output-to-string (lambda () (displayln 10)))))
+
+;; writeln prints with write semantics, followed by a newline
+(assert! (equal? "\"hello\"\n" (with-output-to-string (lambda () (writeln "hello")))))
+(assert! (equal? "#\\a\n" (with-output-to-string (lambda () (writeln #\a)))))
+
+;; Both return void
+(define result #f)
+(with-output-to-string (lambda () (set! result (displayln "x"))))
+(assert! (void? result))
+(with-output-to-string (lambda () (set! result (writeln "x"))))
+(assert! (void? result))
+
+;; writeln accepts an explicit port
+(define port (open-output-string))
+(writeln 42 port)
+(write 1 port)
+(assert! (equal? "42\n1" (get-output-string port)))
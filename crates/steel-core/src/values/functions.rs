@@ -14,7 +14,7 @@ use fxhash::FxHashSet;
 use crate::{
     core::{instructions::DenseInstruction, opcode::OpCode},
     gc::Gc,
-    parser::{parser::SyntaxObjectId, span::Span},
+    parser::{interner::InternedString, parser::SyntaxObjectId, span::Span},
     rvals::{
         from_serializable_value, into_serializable_value, AsRefSteelVal, BoxedFunctionSignature,
         Custom, FunctionSignature, HeapSerializer, IntoSteelVal, MutFunctionSignature,
@@ -96,6 +96,8 @@ impl LambdaMetadataTable {
 #[derive(Clone, Debug)]
 pub struct ByteCodeLambda {
     pub(crate) id: usize,
+    /// name the function was bound to with `define`, if any
+    pub(crate) name: Option<InternedString>,
     /// body of the function with identifiers yet to be bound
     #[cfg(feature = "dynamic")]
     pub(crate) body_exp: RefCell<Rc<[DenseInstruction]>>,
@@ -147,6 +149,7 @@ pub struct SerializedLambda {
     pub body_exp: Vec<DenseInstruction>,
     pub arity: usize,
     pub is_multi_arity: bool,
+    pub name: Option<InternedString>,
     // TODO: Go ahead and create a ThreadSafeSteelVal where we will just deep clone everything, move
     // it across the thread, and reconstruct on the other side.
     pub captures: Vec<SerializableSteelVal>,
@@ -158,6 +161,7 @@ pub struct SerializedLambdaPrototype {
     pub body_exp: Vec<DenseInstruction>,
     pub arity: usize,
     pub is_multi_arity: bool,
+    pub name: Option<InternedString>,
     // TODO: Go ahead and create a ThreadSafeSteelVal where we will just deep clone everything, move
     // it across the thread, and reconstruct on the other side.
     // pub captures: Vec<SerializableSteelVal>,
@@ -179,6 +183,7 @@ impl ByteCodeLambda {
 
         ByteCodeLambda {
             id,
+            name: None,
 
             #[cfg(feature = "dynamic")]
             body_exp: RefCell::new(body_exp),
@@ -205,7 +210,7 @@ impl ByteCodeLambda {
     }
 
     pub(crate) fn from_serialized(heap: &mut HeapSerializer, value: SerializedLambda) -> Self {
-        ByteCodeLambda::new(
+        let mut lambda = ByteCodeLambda::new(
             value.id,
             value.body_exp.into(),
             value.arity,
@@ -216,7 +221,11 @@ impl ByteCodeLambda {
                 .map(|x| from_serializable_value(heap, x))
                 .collect(),
             Vec::new(),
-        )
+        );
+
+        lambda.name = value.name;
+
+        lambda
     }
 
     pub fn main(instructions: Vec<DenseInstruction>) -> ByteCodeLambda {
@@ -147,11 +147,61 @@ macro_rules! port_read_str_fn(
     }};
 );
 
+// Number of bytes in the UTF-8 encoded character starting with this byte
+fn utf8_width(first: u8) -> usize {
+    match first {
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => 1,
+    }
+}
+
+fn decode_utf8_char(bytes: &[u8]) -> Result<char> {
+    match std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|s| s.chars().next())
+    {
+        Some(c) => Ok(c),
+        None => stop!(Generic => "input port contains invalid utf-8: {:?}", bytes),
+    }
+}
+
+fn read_utf8_char<R: Read>(reader: &mut R) -> Result<Option<char>> {
+    let mut buf = [0; 4];
+
+    if reader.read(&mut buf[..1])? == 0 {
+        return Ok(None);
+    }
+
+    let width = utf8_width(buf[0]);
+    reader.read_exact(&mut buf[1..width])?;
+
+    decode_utf8_char(&buf[..width]).map(Some)
+}
+
+fn peek_buffered_utf8_char<R: BufRead>(reader: &mut R) -> Result<Option<char>> {
+    let buf = reader.fill_buf()?;
+
+    if buf.is_empty() {
+        return Ok(None);
+    }
+
+    let width = utf8_width(buf[0]);
+
+    if buf.len() < width {
+        stop!(Generic => "peek-char: unable to look ahead over a partially buffered character");
+    }
+
+    decode_utf8_char(&buf[..width]).map(Some)
+}
+
 impl SteelPortRepr {
     pub fn read_line(&mut self) -> Result<(usize, String)> {
         match self {
             SteelPortRepr::FileInput(_, br) => port_read_str_fn!(br, read_line),
             SteelPortRepr::StdInput(br) => port_read_str_fn!(br, read_line),
+            SteelPortRepr::StringInput(br) => port_read_str_fn!(br, read_line),
 
             SteelPortRepr::ChildStdOutput(br) => {
                 // let buf_reader = BufReader::new(br.borrow_mut().as_mut());
@@ -189,20 +239,37 @@ impl SteelPortRepr {
         }
     }
 
-    pub fn read_char(&mut self) -> Result<(usize, char)> {
-        // FIXME: this only reads 1 u8 and casts it to char
-        macro_rules! port_read_chr(
+    pub fn read_char(&mut self) -> Result<Option<char>> {
+        match self {
+            SteelPortRepr::FileInput(_, br) => read_utf8_char(br),
+            SteelPortRepr::StdInput(br) => read_utf8_char(&mut br.lock()),
+            SteelPortRepr::ChildStdOutput(br) => read_utf8_char(br),
+            SteelPortRepr::StringInput(br) => read_utf8_char(br),
+            _x => stop!(Generic => "read-char"),
+        }
+    }
+
+    pub fn peek_char(&mut self) -> Result<Option<char>> {
+        // Seekable ports can read the character and step back over it, everything
+        // else has to find the whole character in the read buffer
+        macro_rules! port_peek_seekable(
             ($br: ident) => {{
-                let mut chr = [0; 1];
-                $br.read_exact(&mut chr)?;
-                Ok((1, chr[0] as char))
+                let next = read_utf8_char($br)?;
+
+                if let Some(c) = next {
+                    $br.seek_relative(-(c.len_utf8() as i64))?;
+                }
+
+                Ok(next)
             }};
         );
 
         match self {
-            SteelPortRepr::FileInput(_, br) => port_read_chr!(br),
-            SteelPortRepr::StdInput(br) => port_read_chr!(br),
-            _x => stop!(Generic => "read-char"),
+            SteelPortRepr::FileInput(_, br) => port_peek_seekable!(br),
+            SteelPortRepr::StringInput(br) => port_peek_seekable!(br),
+            SteelPortRepr::StdInput(br) => peek_buffered_utf8_char(&mut br.lock()),
+            SteelPortRepr::ChildStdOutput(br) => peek_buffered_utf8_char(br),
+            _x => stop!(Generic => "peek-char"),
         }
     }
 
@@ -286,7 +353,9 @@ impl SteelPortRepr {
     pub fn is_input(&self) -> bool {
         matches!(
             self,
-            SteelPortRepr::FileInput(_, _) | SteelPortRepr::StdInput(_)
+            SteelPortRepr::FileInput(_, _)
+                | SteelPortRepr::StdInput(_)
+                | SteelPortRepr::StringInput(_)
         )
     }
 
@@ -391,10 +460,14 @@ impl SteelPort {
         self.port.borrow_mut().read_all_str()
     }
 
-    pub fn read_char(&self) -> Result<(usize, char)> {
+    pub fn read_char(&self) -> Result<Option<char>> {
         self.port.borrow_mut().read_char()
     }
 
+    pub fn peek_char(&self) -> Result<Option<char>> {
+        self.port.borrow_mut().peek_char()
+    }
+
     pub fn write_char(&self, c: char) -> Result<()> {
         self.port.borrow_mut().write_char(c)
     }
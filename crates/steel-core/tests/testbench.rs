@@ -130,7 +130,7 @@ fn define_test() {
         e,
     );
     test_line("(define a (lambda (x) (+ x 1)))", &["#<void>"], e);
-    test_line("a", &["#<bytecode-closure>"], e);
+    test_line("a", &["#<procedure:a>"], e);
     test_line("(a 2)", &["3"], e);
     test_line("(define (b a1 a2 a3) (+ a1 a2 a3))", &["#<void>"], e);
     test_line("(b 10 20 30)", &["60"], e);
@@ -144,16 +144,16 @@ fn lambda_test() {
     let mut evaluator = Engine::new();
     evaluator.compile_and_run_raw_program(PRELUDE).unwrap();
     let e = &mut evaluator;
-    test_line("(lambda (x) 1 2)", &["#<bytecode-closure>"], e);
+    test_line("(lambda (x) 1 2)", &["#<procedure>"], e);
     // test_line(
     //     "(lambda x 1)",
     //     &["Error: Parse: Parse: Syntax Error: lambda function expected a list of identifiers"],
     //     e,
     // );
-    test_line("(lambda () 1)", &["#<bytecode-closure>"], e);
+    test_line("(lambda () 1)", &["#<procedure>"], e);
     test_line(
         "(lambda () (lambda () (lambda () (lambda () 1))))",
-        &["#<bytecode-closure>"],
+        &["#<procedure>"],
         e,
     );
     test_line(
@@ -1476,7 +1476,7 @@ where
 
             let mut args = l.args.into_iter();
 
-            let name = args.next().ok_or_else(|| {
+            let mut name = args.next().ok_or_else(|| {
                 ParseError::SyntaxError(
                     "define expected a function name, found none".to_string(),
                     syn.span,
@@ -1484,7 +1484,7 @@ where
                 )
             })?;
 
-            let args = args.collect();
+            let mut args = args.collect();
 
             let body_exprs: Vec<_> = value_iter.collect();
 
@@ -1496,7 +1496,7 @@ where
                 ));
             }
 
-            let body = if body_exprs.len() == 1 {
+            let mut body = if body_exprs.len() == 1 {
                 body_exprs[0].clone()
             } else {
                 ExprKind::Begin(Begin::new(
@@ -1505,6 +1505,33 @@ where
                 ))
             };
 
+            // Curried define, e.g. (define ((adder n) x) (+ n x)). Each nested
+            // head becomes another lambda wrapping the one built so far.
+            while let ExprKind::List(inner) = &name {
+                if inner.first_ident() == Some(&*DATUM_SYNTAX) {
+                    break;
+                }
+
+                let mut inner_args = inner.args.clone().into_iter();
+
+                let inner_name = inner_args.next().ok_or_else(|| {
+                    ParseError::SyntaxError(
+                        "define expected a function name, found none".to_string(),
+                        syn.span,
+                        None,
+                    )
+                })?;
+
+                body = ExprKind::LambdaFunction(Box::new(LambdaFunction::new(
+                    args,
+                    body,
+                    SyntaxObject::new(TokenType::Lambda, syn.span),
+                )));
+
+                name = inner_name;
+                args = inner_args.collect();
+            }
+
             let lambda = ExprKind::LambdaFunction(Box::new(LambdaFunction::new(
                 args,
                 body,
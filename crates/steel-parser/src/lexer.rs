@@ -71,6 +71,21 @@ impl<'a> Lexer<'a> {
         // Skip the opening quote.
         self.eat();
 
+        // Most string literals don't contain escapes, in which case the contents can be
+        // copied straight out of the source with one allocation, rather than being built
+        // up a character at a time.
+        let rest = &self.source[self.token_end..];
+        if let Some(end) = rest.find(['"', '\\']) {
+            if rest.as_bytes()[end] == b'"' {
+                let literal = rest[..end].to_string();
+
+                self.token_end += end + 1;
+                self.chars = self.source[self.token_end..].chars().peekable();
+
+                return Ok(TokenType::StringLiteral(literal));
+            }
+        }
+
         let mut buf = String::new();
         while let Some(&c) = self.chars.peek() {
             self.eat();
@@ -117,6 +132,32 @@ impl<'a> Lexer<'a> {
         Err(TokenError::IncompleteString)
     }
 
+    // Skips the rest of a `#| ... |#` comment, once the opening `#|` has been consumed.
+    // Block comments nest, so each `#|` inside needs its own matching `|#`.
+    fn read_block_comment(&mut self) -> Result<TokenType<&'a str>> {
+        let mut depth = 1;
+
+        while let Some(c) = self.eat() {
+            match c {
+                '|' if self.chars.peek() == Some(&'#') => {
+                    self.eat();
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Ok(TokenType::Comment);
+                    }
+                }
+                '#' if self.chars.peek() == Some(&'|') => {
+                    self.eat();
+                    depth += 1;
+                }
+                _ => {}
+            }
+        }
+
+        Err(TokenError::IncompleteBlockComment)
+    }
+
     fn read_hash_value(&mut self) -> Result<TokenType<&'a str>> {
         fn parse_char(slice: &str) -> Option<char> {
             use std::str::FromStr;
@@ -131,6 +172,8 @@ impl<'a> Lexer<'a> {
                 "#\\newline" => Some('\n'),
                 "#\\return" => Some('\r'),
                 "#\\RETURN" => Some('\r'),
+                "#\\nul" => Some('\0'),
+                "#\\NUL" => Some('\0'),
                 "#\\)" => Some(')'),
                 "#\\]" => Some(']'),
                 "#\\[" => Some('['),
@@ -214,7 +257,7 @@ impl<'a> Lexer<'a> {
         while let Some(&c) = self.chars.peek() {
             match c {
                 c if c.is_numeric() => self.eat(),
-                '(' | ')' | '[' | ']' => break,
+                '(' | ')' | '[' | ']' | ';' => break,
                 '.' | '/' => break,
                 'e' | 'E' => {
                     has_e = true;
@@ -239,7 +282,11 @@ impl<'a> Lexer<'a> {
                             has_e = true;
                             self.eat();
                         }
-                        '(' | '[' | ')' | ']' => break,
+                        // The exponent may be signed, as in 1e-7
+                        '-' | '+' if matches!(self.slice().chars().last(), Some('e' | 'E')) => {
+                            self.eat();
+                        }
+                        '(' | '[' | ')' | ']' | ';' => break,
                         c if c.is_whitespace() => break,
                         _ => {
                             self.eat();
@@ -249,7 +296,7 @@ impl<'a> Lexer<'a> {
                 }
                 let text = self.slice();
                 match text.chars().last() {
-                    Some('e') | Some('E') => self.read_word(),
+                    Some('e') | Some('E') | Some('-') | Some('+') => self.read_word(),
                     _ => TokenType::NumberLiteral(text.parse().unwrap()),
                 }
             }
@@ -261,7 +308,7 @@ impl<'a> Lexer<'a> {
                         c if c.is_numeric() => {
                             self.eat();
                         }
-                        '(' | '[' | ')' | ']' => break,
+                        '(' | '[' | ')' | ']' | ';' => break,
                         c if c.is_whitespace() => break,
                         _ => {
                             self.eat();
@@ -293,7 +340,7 @@ impl<'a> Lexer<'a> {
     fn read_word(&mut self) -> TokenType<&'a str> {
         while let Some(&c) = self.chars.peek() {
             match c {
-                '(' | '[' | ')' | ']' => break,
+                '(' | '[' | ')' | ']' | ';' => break,
                 c if c.is_whitespace() => break,
                 '\'' => {
                     break;
@@ -423,6 +470,7 @@ pub enum TokenError {
     MalformedHexInteger,
     MalformedOctalInteger,
     MalformedBinaryInteger,
+    IncompleteBlockComment,
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -490,7 +538,16 @@ impl<'a> Iterator for Lexer<'a> {
             }
             Some('#') => {
                 self.eat();
-                Some(self.read_hash_value())
+
+                if self.chars.peek() == Some(&'|') {
+                    self.eat();
+                    Some(self.read_block_comment())
+                } else if self.chars.peek() == Some(&';') {
+                    self.eat();
+                    Some(Ok(TokenType::DatumComment))
+                } else {
+                    Some(self.read_hash_value())
+                }
             }
 
             Some(c) if !c.is_whitespace() && !c.is_numeric() || *c == '_' => {
@@ -793,6 +850,36 @@ mod lexer_tests {
         );
     }
 
+    #[test]
+    fn test_signed_exponents() {
+        let got: Vec<_> = TokenStream::new("1e-7 2.5E+3 1e- 1e-2-", true, None).collect();
+        assert_eq!(
+            got.as_slice(),
+            &[
+                Token {
+                    ty: NumberLiteral(1e-7),
+                    source: "1e-7",
+                    span: Span::new(0, 4, None),
+                },
+                Token {
+                    ty: NumberLiteral(2500.0),
+                    source: "2.5E+3",
+                    span: Span::new(5, 11, None),
+                },
+                Token {
+                    ty: Identifier("1e-"),
+                    source: "1e-",
+                    span: Span::new(12, 15, None),
+                },
+                Token {
+                    ty: Identifier("1e-2-"),
+                    source: "1e-2-",
+                    span: Span::new(16, 21, None),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_fractions() {
         let got: Vec<_> = TokenStream::new(
@@ -910,12 +997,92 @@ mod lexer_tests {
         );
     }
 
+    #[test]
+    fn test_string_followed_by_tokens() {
+        let got: Vec<_> = TokenStream::new(r#"(f "héllo" "a\nb" x)"#, true, None).collect();
+        assert_eq!(
+            got.as_slice(),
+            &[
+                Token {
+                    ty: OpenParen,
+                    source: "(",
+                    span: Span::new(0, 1, None),
+                },
+                Token {
+                    ty: Identifier("f"),
+                    source: "f",
+                    span: Span::new(1, 2, None),
+                },
+                Token {
+                    ty: StringLiteral("héllo".to_string()),
+                    source: r#""héllo""#,
+                    span: Span::new(3, 11, None),
+                },
+                Token {
+                    ty: StringLiteral("a\nb".to_string()),
+                    source: r#""a\nb""#,
+                    span: Span::new(12, 18, None),
+                },
+                Token {
+                    ty: Identifier("x"),
+                    source: "x",
+                    span: Span::new(19, 20, None),
+                },
+                Token {
+                    ty: CloseParen,
+                    source: ")",
+                    span: Span::new(20, 21, None),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_comment() {
         let mut s = TokenStream::new(";!/usr/bin/gate\n   ; foo\n", true, None);
         assert_eq!(s.next(), None);
     }
 
+    #[test]
+    fn test_block_comment() {
+        let got: Vec<_> = TokenStream::new("a #| skipped\n (b) |# c", true, None)
+            .map(|x| x.ty)
+            .collect();
+        assert_eq!(got, vec![Identifier("a"), Identifier("c")]);
+    }
+
+    #[test]
+    fn test_nested_block_comment() {
+        let got: Vec<_> = TokenStream::new("#| outer #| inner |# still outer |# d", true, None)
+            .map(|x| x.ty)
+            .collect();
+        assert_eq!(got, vec![Identifier("d")]);
+    }
+
+    #[test]
+    fn test_unterminated_block_comment() {
+        let mut lexer = Lexer::new("(a #| never #| closed |# ");
+        assert_eq!(lexer.next(), Some(Ok(OpenParen)));
+        assert_eq!(lexer.next(), Some(Ok(Identifier("a"))));
+        assert_eq!(lexer.next(), Some(Err(TokenError::IncompleteBlockComment)));
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[test]
+    fn test_comment_directly_after_atom() {
+        let got: Vec<_> = TokenStream::new("foo;one\n12;two\n1.5;three", true, None)
+            .map(|x| x.ty)
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                Identifier("foo"),
+                IntegerLiteral(MaybeBigInt::Small(12)),
+                NumberLiteral(1.5)
+            ]
+        );
+    }
+
     #[test]
     fn function_definition() {
         let s = TokenStream::new(
@@ -1042,4 +1209,52 @@ mod lexer_tests {
 
         assert_eq!(res, expected);
     }
+
+    #[test]
+    fn test_datum_comment() {
+        let got: Vec<_> = TokenStream::new("(+ 1 #;2 3)", true, None)
+            .map(|x| x.ty)
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                OpenParen,
+                Identifier("+"),
+                IntegerLiteral(MaybeBigInt::Small(1)),
+                DatumComment,
+                IntegerLiteral(MaybeBigInt::Small(2)),
+                IntegerLiteral(MaybeBigInt::Small(3)),
+                CloseParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_named_chars() {
+        let got: Vec<_> = TokenStream::new(
+            "#\\space #\\newline #\\tab #\\nul #\\return #\\x",
+            true,
+            None,
+        )
+        .map(|x| x.ty)
+        .collect();
+        assert_eq!(
+            got,
+            vec![
+                CharacterLiteral(' '),
+                CharacterLiteral('\n'),
+                CharacterLiteral('\t'),
+                CharacterLiteral('\0'),
+                CharacterLiteral('\r'),
+                CharacterLiteral('x'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bare_char_at_eof() {
+        let mut lexer = Lexer::new("#\\");
+        assert_eq!(lexer.next(), Some(Err(TokenError::InvalidCharacter)));
+        assert_eq!(lexer.next(), None);
+    }
 }
@@ -281,6 +281,8 @@ fn tokentype_error_to_parse_error(t: &Token<'_, InternedString>) -> ParseError {
 
         if t.source.starts_with('\"') {
             ParseError::IncompleteString(t.source.to_string(), t.span, None)
+        } else if t.source.starts_with("#|") {
+            ParseError::SyntaxError("unterminated block comment".to_string(), t.span, None)
         } else {
             ParseError::UnexpectedChar(t.source.chars().next().unwrap(), t.span, None)
         }
@@ -499,6 +501,10 @@ impl<'a> Parser<'a> {
                             continue;
                         }
                         TokenType::Error => return Err(tokentype_error_to_parse_error(&token)), // TODO
+                        TokenType::DatumComment => {
+                            self.skip_datum()?;
+                            continue;
+                        }
                         TokenType::QuoteTick => {
                             // quote_count += 1;
                             // self.quote_stack.push(current_frame.len());
@@ -944,6 +950,13 @@ fn wrap_in_doc_function(expr: ExprKind, comment: String) -> ExprKind {
 }
 
 impl<'a> Parser<'a> {
+    // Reads the next complete datum following a `#;` and throws it away
+    fn skip_datum(&mut self) -> Result<()> {
+        self.get_next_and_maybe_wrap_in_doc()
+            .unwrap_or(Err(ParseError::UnexpectedEOF(self.source_name.clone())))
+            .map(|_| ())
+    }
+
     fn get_next_and_maybe_wrap_in_doc(&mut self) -> Option<Result<ExprKind>> {
         let mut next;
 
@@ -981,6 +994,14 @@ impl<'a> Parser<'a> {
                         continue;
                     }
 
+                    TokenType::DatumComment => {
+                        if let Err(e) = self.skip_datum() {
+                            return Some(Err(e));
+                        }
+
+                        continue;
+                    }
+
                     TokenType::QuoteTick => {
                         // See if this does the job
                         self.shorthand_quote_stack.push(0);
@@ -1523,6 +1544,12 @@ mod parser_tests {
         assert_eq!(a.as_slice(), result);
     }
 
+    fn assert_parses_same(s: &str, expected: &str) {
+        let a: Result<Vec<ExprKind>> = Parser::new(s, None).collect();
+        let b: Result<Vec<ExprKind>> = Parser::new(expected, None).collect();
+        assert_eq!(a.unwrap(), b.unwrap());
+    }
+
     fn assert_parse_err(s: &str, err: ParseError) {
         let a: Result<Vec<ExprKind>> = Parser::new(s, None).collect();
         assert_eq!(a, Err(err));
@@ -1687,6 +1714,20 @@ mod parser_tests {
         assert_parse("#\\(", &[character('(')])
     }
 
+    #[test]
+    fn parse_named_characters() {
+        assert_parse(
+            "#\\space #\\newline #\\tab #\\nul",
+            &[
+                character(' '),
+                character('\n'),
+                character('\t'),
+                character('\0'),
+            ],
+        );
+        assert_parse_is_err("#\\");
+    }
+
     #[test]
     fn test_error() {
         assert_parse_err("(", ParseError::UnexpectedEOF(None));
@@ -1715,7 +1756,6 @@ mod parser_tests {
     fn test_if_should_err() {
         assert_parse_is_err("(if)");
         assert_parse_is_err("(if 1)");
-        // assert_parse_is_err("(if 1 2)");
         assert_parse_is_err("(if 1 2 3 4)");
     }
 
@@ -1879,6 +1919,19 @@ mod parser_tests {
         );
     }
 
+    #[test]
+    fn test_if_without_else() {
+        assert_parse(
+            "(if 1 2)",
+            &[ExprKind::If(Box::new(If::new(
+                int(1),
+                int(2),
+                atom("#%prim.void"),
+                SyntaxObject::default(TokenType::If),
+            )))],
+        );
+    }
+
     #[test]
     fn test_quote() {
         assert_parse(
@@ -2043,6 +2096,55 @@ mod parser_tests {
         )
     }
 
+    #[test]
+    fn test_quasiquote_shorthand_atom() {
+        assert_parse(
+            "`foo",
+            &[ExprKind::List(List::new(vec![
+                atom("quasiquote"),
+                atom("foo"),
+            ]))],
+        )
+    }
+
+    #[test]
+    fn test_quasiquote_shorthand_nested() {
+        assert_parse(
+            "`(a `(b))",
+            &[ExprKind::List(List::new(vec![
+                atom("quasiquote"),
+                ExprKind::List(List::new(vec![
+                    atom("a"),
+                    ExprKind::List(List::new(vec![
+                        atom("quasiquote"),
+                        ExprKind::List(List::new(vec![atom("b")])),
+                    ])),
+                ])),
+            ]))],
+        )
+    }
+
+    #[test]
+    fn test_quasiquote_shorthand_matches_normal() {
+        assert_parses_same("`(+ 1 (* 2 3))", "(quasiquote (+ 1 (* 2 3)))");
+        assert_parses_same("`(a `(b))", "(quasiquote (a (quasiquote (b))))");
+        assert_parses_same("(list `x `(y))", "(list (quasiquote x) (quasiquote (y)))");
+    }
+
+    #[test]
+    fn test_quasiquote_shorthand_multiple_exprs() {
+        assert_parse(
+            "`(a b) (+ 1 2)",
+            &[
+                ExprKind::List(List::new(vec![
+                    atom("quasiquote"),
+                    ExprKind::List(List::new(vec![atom("a"), atom("b")])),
+                ])),
+                ExprKind::List(List::new(vec![atom("+"), int(1), int(2)])),
+            ],
+        )
+    }
+
     #[test]
     fn test_unquote_shorthand() {
         assert_parse(
@@ -2087,6 +2189,55 @@ mod parser_tests {
         )
     }
 
+    // Directly inside a quasiquote the reader lowers the shorthand to the raw
+    // `#%unquote` / `#%unquote-splicing` forms that the quasiquote macro expects
+    #[test]
+    fn test_unquote_shorthand_inside_quasiquote() {
+        assert_parses_same(
+            "`(a ,b ,@c)",
+            "(quasiquote (a (#%unquote b) (#%unquote-splicing c)))",
+        );
+        assert_parses_same(
+            "`(a ,(+ 1 2) ,@(list 3 4))",
+            "(quasiquote (a (#%unquote (+ 1 2)) (#%unquote-splicing (list 3 4))))",
+        );
+    }
+
+    #[test]
+    fn test_unquote_splicing_outside_list() {
+        assert_parses_same(",@foo", "(unquote-splicing foo)");
+        assert_parses_same("`,@foo", "(quasiquote (#%unquote-splicing foo))");
+    }
+
+    #[test]
+    fn test_unquote_at_eof() {
+        assert_parse_err(",", ParseError::UnexpectedEOF(None));
+        assert_parse_err(",@", ParseError::UnexpectedEOF(None));
+        assert_parse_err("`(a ,", ParseError::UnexpectedEOF(None));
+    }
+
+    #[test]
+    fn test_unquote_display_round_trip() {
+        for source in [
+            ",x",
+            ",@x",
+            "`(a ,b ,@c)",
+            "`(1 `(2 ,(3 ,x)))",
+            "`(x ,@(map f xs))",
+        ] {
+            let parsed: Vec<ExprKind> = Parser::new(source, None).collect::<Result<_>>().unwrap();
+            let printed = parsed
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let reparsed: Vec<ExprKind> =
+                Parser::new(&printed, None).collect::<Result<_>>().unwrap();
+
+            assert_eq!(parsed, reparsed, "{source} printed as {printed}");
+        }
+    }
+
     #[test]
     fn test_define_simple() {
         assert_parse(
@@ -2226,6 +2377,29 @@ mod parser_tests {
         )
     }
 
+    #[test]
+    fn test_lambda_multiple_body_expressions() {
+        assert_parse(
+            "(lambda (x) (display x) (+ x 1))",
+            &[ExprKind::LambdaFunction(Box::new(LambdaFunction::new(
+                vec![atom("x")],
+                ExprKind::Begin(Begin::new(
+                    vec![
+                        ExprKind::List(List::new(vec![atom("display"), atom("x")])),
+                        ExprKind::List(List::new(vec![atom("+"), atom("x"), int(1)])),
+                    ],
+                    SyntaxObject::default(TokenType::Begin),
+                )),
+                SyntaxObject::default(TokenType::Lambda),
+            )))],
+        )
+    }
+
+    #[test]
+    fn test_lambda_empty_body_should_err() {
+        assert_parse_is_err("(lambda (x))");
+    }
+
     #[test]
     fn test_lambda_matches_let() {
         assert_parse(
@@ -2421,6 +2595,26 @@ mod parser_tests {
         )
     }
 
+    #[test]
+    fn test_define_curried_function() {
+        assert_parse(
+            "(define ((adder n) x) (+ n x))",
+            &[ExprKind::Define(Box::new(Define::new(
+                atom("adder"),
+                ExprKind::LambdaFunction(Box::new(LambdaFunction::new(
+                    vec![atom("n")],
+                    ExprKind::LambdaFunction(Box::new(LambdaFunction::new(
+                        vec![atom("x")],
+                        ExprKind::List(List::new(vec![atom("+"), atom("n"), atom("x")])),
+                        SyntaxObject::default(TokenType::Lambda),
+                    ))),
+                    SyntaxObject::default(TokenType::Lambda),
+                ))),
+                SyntaxObject::default(TokenType::Define),
+            )))],
+        )
+    }
+
     #[test]
     fn test_parse_without_lowering_ast() {
         let a: Result<Vec<ExprKind>> =
@@ -2482,4 +2676,49 @@ mod parser_tests {
 
         println!("{:#?}", a);
     }
+
+    #[test]
+    fn test_line_comments_are_skipped() {
+        assert_parses_same("(+ 1 2) ; add them", "(+ 1 2)");
+        assert_parses_same(
+            "; a leading comment\n(define x 10) ; trailing\n; between\n(+ x 1)\n; at the end",
+            "(define x 10) (+ x 1)",
+        );
+        assert_parses_same(
+            "(list 1 ; one\n      2 ; two\n      3) ; three",
+            "(list 1 2 3)",
+        );
+        assert_parses_same("(foo;comment\n bar)", "(foo bar)");
+        assert_parses_same("'(a ; hidden\n b)", "'(a b)");
+    }
+
+    #[test]
+    fn test_semicolon_in_string_is_not_a_comment() {
+        assert_parse(
+            "(display \"a;b\") ; real comment",
+            &[ExprKind::List(List::new(vec![
+                atom("display"),
+                ExprKind::string_lit("a;b".to_string()),
+            ]))],
+        );
+        assert_parses_same("\"; not a comment\"\n; but this is", "\"; not a comment\"");
+    }
+
+    #[test]
+    fn test_block_comments_are_skipped() {
+        assert_parses_same("(+ 1 #| two\n three |# 2)", "(+ 1 2)");
+        assert_parses_same("#| outer #| inner |# still outer |# (foo)", "(foo)");
+        assert_parse_is_err("(+ 1 #| never closed");
+    }
+
+    #[test]
+    fn test_datum_comments_are_skipped() {
+        assert_parses_same("(+ 1 #;2 3)", "(+ 1 3)");
+        assert_parses_same("(+ 1 #; (foo (bar 2)) 3)", "(+ 1 3)");
+        assert_parses_same("(list #;'(1 2) 'a)", "(list 'a)");
+        assert_parses_same("#;(define x 10) (define y 20)", "(define y 20)");
+        assert_parses_same("(a #;#;b c d)", "(a d)");
+        assert_parse_is_err("(+ 1 #;)");
+        assert_parse_is_err("#;");
+    }
 }
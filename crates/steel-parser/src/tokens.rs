@@ -112,6 +112,7 @@ pub enum TokenType<S> {
     Require,
     CharacterLiteral(char),
     Comment,
+    DatumComment,
     BooleanLiteral(bool),
     Identifier(S),
     Keyword(S),
@@ -180,6 +181,7 @@ impl<'a> TokenType<&'a str> {
             UnquoteSplice => UnquoteSplice,
             Error => Error,
             Comment => Comment,
+            DatumComment => DatumComment,
             If => If,
             Define => Define,
             Let => Let,
@@ -218,6 +220,7 @@ impl<'a> TokenType<&'a str> {
             UnquoteSplice => UnquoteSplice,
             Error => Error,
             Comment => Comment,
+            DatumComment => DatumComment,
             If => If,
             Define => Define,
             Let => Let,
@@ -279,6 +282,7 @@ impl<T: fmt::Display> fmt::Display for TokenType<T> {
             UnquoteSpliceSyntax => write!(f, "#,@"),
             Error => write!(f, "error"),
             Comment => write!(f, ""),
+            DatumComment => write!(f, "#;"),
             If => write!(f, "if"),
             Define => write!(f, "define"),
             Let => write!(f, "let"),
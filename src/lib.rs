@@ -80,6 +80,10 @@ pub fn run(clap_args: Args) -> Result<(), Box<dyn Error>> {
             let res = vm.compile_and_run_raw_program_with_path(contents.clone(), path.clone());
 
             if let Err(e) = res {
+                if let Some(code) = e.exit_code() {
+                    process::exit(code);
+                }
+
                 e.emit_result(path.to_str().unwrap(), &contents);
                 // process::exit(1);
 